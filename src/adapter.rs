@@ -0,0 +1,117 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Adapters used to pick which encoding is used when formatting a UUID.
+//!
+//! All of the adapters implement [Display](fmt::Display) and honor the width,
+//! fill, and alignment flags of the formatter. The hexadecimal based adapters
+//! also implement [LowerHex](fmt::LowerHex) and [UpperHex](fmt::UpperHex) so
+//! `{:X}` gives upper case digits.
+
+use crate::codec;
+use core::fmt;
+
+/// Formats a UUID using the custom base 64 encoding.
+///
+/// See [Uuid::base64](crate::Uuid::base64).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Base64(pub(crate) u128);
+
+/// Formats a UUID as 32 hexadecimal digits without hyphens.
+///
+/// See [Uuid::simple](crate::Uuid::simple).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Simple(pub(crate) u128);
+
+/// Formats a UUID as a standard hyphenated uuid.
+///
+/// See [Uuid::hyphenated](crate::Uuid::hyphenated).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Hyphenated(pub(crate) u128);
+
+/// Formats a UUID as an `urn:uuid:` URN.
+///
+/// See [Uuid::urn](crate::Uuid::urn).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Urn(pub(crate) u128);
+
+/// Formats a UUID as a Microsoft style `{...}` braced uuid.
+///
+/// See [Uuid::braced](crate::Uuid::braced).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Braced(pub(crate) u128);
+
+impl fmt::Display for Base64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 22];
+        f.pad(codec::encode_base64(self.0, &codec::BASE64_ALPHABET, &mut buf))
+    }
+}
+
+/// Implements `Display`, `LowerHex` and `UpperHex` for a hexadecimal based
+/// adapter.
+///
+/// ## Arguments
+/// * `$t` - The adapter type.
+/// * `$len` - Length of the encoded string.
+/// * `$encode` - Codec function taking the value, digits, and buffer.
+macro_rules! impl_hex_adapter {
+    ($t:ty, $len:expr, $encode:path) => {
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl fmt::LowerHex for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [0u8; $len];
+                f.pad($encode(self.0, &codec::HEX_DIGITS, &mut buf))
+            }
+        }
+
+        impl fmt::UpperHex for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [0u8; $len];
+                f.pad($encode(self.0, &codec::HEX_DIGITS_UPPER, &mut buf))
+            }
+        }
+    };
+}
+
+impl_hex_adapter!(Simple, 32, codec::encode_hex_with);
+impl_hex_adapter!(Hyphenated, 36, codec::encode_uuid_with);
+impl_hex_adapter!(Urn, 45, codec::encode_urn);
+impl_hex_adapter!(Braced, 38, codec::encode_braced);
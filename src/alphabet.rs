@@ -0,0 +1,223 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Generic fixed-width encoding engine for custom alphabets.
+//!
+//! Any alphabet of 2 to 255 ASCII symbols can be used to encode the 128 bit
+//! value as a big number in that base. The output is always zero padded to
+//! the number of symbols needed for the largest value so every encoded string
+//! of an alphabet has the same length and sorts like the values do when the
+//! symbols are in ASCII order.
+
+use crate::{
+    codec::{self, INVALID},
+    Format, ParseError, ParseErrorKind, U64Error,
+};
+
+/// A set of symbols used to encode and decode UUIDs.
+///
+/// See [Uuid::encode](crate::Uuid::encode).
+///
+/// ```
+/// use uuid64rs::{Alphabet, Uuid, Uuid4};
+///
+/// let id = Uuid4::default();
+/// let mut buf = [0u8; Alphabet::MAX_WIDTH];
+/// let encoded = Alphabet::BASE58_BITCOIN.encode(id.uuid0(), &mut buf);
+/// assert_eq!(encoded.len(), 22);
+/// let decoded = Uuid4::decode(encoded, &Alphabet::BASE58_BITCOIN).unwrap();
+/// assert_eq!(decoded, id);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Alphabet {
+    symbols: &'static [u8],
+    table: [u8; 256],
+    width: usize,
+}
+
+impl Alphabet {
+    /// Length of the longest encoded string of any alphabet which is used by
+    /// base 2.
+    pub const MAX_WIDTH: usize = 128;
+    /// Lower case hexadecimal digits. Decoding ignores case.
+    pub const BASE16: Alphabet =
+        Alphabet::new(b"0123456789abcdef").case_insensitive();
+    /// Douglas Crockford's base 32 symbols. Decoding ignores case.
+    ///
+    /// See [as_crockford](crate::Uuid::as_crockford) for a decoder that also
+    /// accepts the symbols that are easily confused.
+    pub const BASE32_CROCKFORD: Alphabet =
+        Alphabet::new(&codec::CROCKFORD_ALPHABET).case_insensitive();
+    /// Digits followed by the lower case letters. Decoding ignores case.
+    pub const BASE36: Alphabet =
+        Alphabet::new(b"0123456789abcdefghijklmnopqrstuvwxyz")
+            .case_insensitive();
+    /// The symbols used by Bitcoin addresses which leave out `0`, `I`, `O`,
+    /// and `l`.
+    pub const BASE58_BITCOIN: Alphabet = Alphabet::new(
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    );
+    /// Digits followed by the upper and then lower case letters.
+    pub const BASE62: Alphabet = Alphabet::new(
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    );
+    /// The custom base 64 alphabet of [as_base64](crate::Uuid::as_base64).
+    pub const BASE64: Alphabet = Alphabet::new(&codec::BASE64_ALPHABET);
+    /// The sortable custom base 64 alphabet of
+    /// [as_base64_sortable](crate::Uuid::as_base64_sortable).
+    pub const BASE64_SORTABLE: Alphabet =
+        Alphabet::new(&codec::BASE64_SORTABLE_ALPHABET);
+    /// Construct a new alphabet.
+    ///
+    /// The position of each symbol is its digit value.
+    ///
+    /// ## Arguments
+    /// * `symbols` - 2 to 255 unique ASCII symbols.
+    ///
+    /// ## Panics
+    /// When `symbols` has the wrong length, a repeated symbol, or a non-ASCII
+    /// byte. Used in a `const` this is a compile time error instead.
+    pub const fn new(symbols: &'static [u8]) -> Self {
+        let base = symbols.len();
+        assert!(base >= 2 && base <= 255, "Alphabet needs 2 to 255 symbols");
+        let table = codec::decode_table(symbols);
+        let mut i = 0;
+        while i < base {
+            assert!(symbols[i].is_ascii(), "Alphabet symbols must be ASCII");
+            assert!(
+                table[symbols[i] as usize] as usize == i,
+                "Alphabet symbols must be unique"
+            );
+            i += 1;
+        }
+        // The number of digits needed for the largest value.
+        let mut width = 0;
+        let mut max = u128::MAX;
+        while max > 0 {
+            max /= base as u128;
+            width += 1;
+        }
+        Self { symbols, table, width }
+    }
+    /// Returns a copy that also decodes the other case of each letter.
+    ///
+    /// Letters whose other case is already a symbol are left alone.
+    pub const fn case_insensitive(mut self) -> Self {
+        let mut i = 0;
+        while i < self.symbols.len() {
+            let symbol = self.symbols[i];
+            if symbol.is_ascii_alphabetic() {
+                let other = (symbol ^ 0x20) as usize;
+                if self.table[other] == INVALID {
+                    self.table[other] = i as u8;
+                }
+            }
+            i += 1;
+        }
+        self
+    }
+    /// Returns a copy that also decodes `alias` in either case as `symbol`.
+    pub(crate) const fn alias(mut self, alias: u8, symbol: u8) -> Self {
+        let digit = self.table[symbol as usize];
+        self.table[alias.to_ascii_uppercase() as usize] = digit;
+        self.table[alias.to_ascii_lowercase() as usize] = digit;
+        self
+    }
+    /// The digit value of a symbol if it can be decoded.
+    pub(crate) const fn digit(&self, symbol: u8) -> Option<u8> {
+        match self.table[symbol as usize] {
+            INVALID => None,
+            digit => Some(digit),
+        }
+    }
+    /// The number of symbols in the alphabet.
+    pub const fn base(&self) -> usize {
+        self.symbols.len()
+    }
+    /// The length of every encoded string.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    /// Encodes the value into a caller provided buffer.
+    ///
+    /// Returns a view of the first [width](Self::width) bytes of the buffer so
+    /// no allocation is needed.
+    pub fn encode<'a>(
+        &self,
+        value: u128,
+        buf: &'a mut [u8; Self::MAX_WIDTH],
+    ) -> &'a str {
+        let base = self.base() as u128;
+        let out = &mut buf[..self.width];
+        let mut value = value;
+        for b in out.iter_mut().rev() {
+            *b = self.symbols[(value % base) as usize];
+            value /= base;
+        }
+        core::str::from_utf8(out).expect("Alphabet symbols are ASCII")
+    }
+    /// Decodes a string made with [encode](Self::encode).
+    ///
+    /// ## Arguments
+    /// * `value` - Exactly [width](Self::width) symbols.
+    pub fn decode(&self, value: &[u8]) -> Result<u128, U64Error> {
+        if value.len() != self.width {
+            let len = value.len();
+            return Err(U64Error::invalid_length(Format::Custom, len));
+        }
+        let base = self.base() as u128;
+        let mut result = 0u128;
+        for (i, b) in value.iter().enumerate() {
+            let digit = self.table[*b as usize];
+            if digit == INVALID {
+                let e = ParseError::invalid_character(Format::Custom, value, i);
+                return Err(U64Error::InvalidEncodedString(e));
+            }
+            result = match result
+                .checked_mul(base)
+                .and_then(|r| r.checked_add(digit as u128))
+            {
+                Some(r) => r,
+                None => {
+                    let kind = ParseErrorKind::Overflow;
+                    let e = ParseError::new(Format::Custom, i, kind);
+                    return Err(U64Error::InvalidEncodedString(e));
+                }
+            };
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! A UUID type that keeps whatever version and variant bits it is given.
+
+#[cfg(feature = "v3")]
+use crate::Uuid3;
+#[cfg(feature = "v5")]
+use crate::Uuid5;
+use crate::{U64Error, Uuid, Uuid4, Uuid4Rfc, Uuid7};
+use core::convert::TryFrom;
+
+/// Any UUID regardless of its version or variant.
+///
+/// Accepts all of the same formats as the other types but keeps the version
+/// and variant bits as they are instead of replacing them. Use
+/// [version](AnyUuid::version) to find out what was parsed and `TryFrom` to
+/// narrow it to one of the other types once the bits match.
+///
+/// __NOTE:__ _The version and variant are read from the RFC 9562 layout.
+/// Values made by [Uuid4] are migrated from its legacy layout the same way as
+/// [Uuid4Rfc] does in both directions, so they report version 4 here._
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AnyUuid(u128);
+
+impl_uuid_common!(
+    AnyUuid,
+    u128::from_be_bytes,
+    u128::to_be_bytes,
+    u128::MAX,
+    0
+);
+#[cfg(feature = "serde")]
+impl_uuid_serde!(AnyUuid, u128::to_be_bytes);
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+impl_uuid_interop!(AnyUuid, u128::from_be_bytes, u128::to_be_bytes);
+
+/// Implements the conversions between [AnyUuid] and the other UUID types.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+macro_rules! impl_any_uuid {
+    ($($t:ty),+) => {$(
+        impl From<$t> for AnyUuid {
+            fn from(value: $t) -> Self {
+                Self(value.uuid0())
+            }
+        }
+
+        impl TryFrom<AnyUuid> for $t {
+            type Error = U64Error;
+
+            /// Returns
+            /// [InvalidVersionOrVariant](U64Error::InvalidVersionOrVariant)
+            /// unless the bits already are the ones of the type.
+            fn try_from(value: AnyUuid) -> Result<Self, Self::Error> {
+                Self::from_u128_strict(value.0)
+            }
+        }
+    )+};
+}
+
+impl_any_uuid!(Uuid4Rfc, Uuid7);
+#[cfg(feature = "v3")]
+impl_any_uuid!(Uuid3);
+#[cfg(feature = "v5")]
+impl_any_uuid!(Uuid5);
+
+impl From<Uuid4> for AnyUuid {
+    /// Migrates the value from the legacy layout.
+    fn from(value: Uuid4) -> Self {
+        Self(value.uuid0().swap_bytes())
+    }
+}
+
+impl TryFrom<AnyUuid> for Uuid4 {
+    type Error = U64Error;
+
+    /// Returns [InvalidVersionOrVariant](U64Error::InvalidVersionOrVariant)
+    /// unless the RFC 9562 version 4 bits are set.
+    fn try_from(value: AnyUuid) -> Result<Self, Self::Error> {
+        Uuid4Rfc::try_from(value).map(Self::from)
+    }
+}
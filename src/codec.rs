@@ -0,0 +1,684 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Shared encoders and decoders used by all of the UUID types in the library.
+//!
+//! The decoders only turn the text into the raw `u128` value. Each type is
+//! responsible for applying its own version and variant bits afterwards.
+
+use crate::{
+    Alphabet, Format, ParseError, ParseErrorKind, ParseMode, U64Error,
+};
+use core::convert::TryInto;
+
+/// Decodes any of the supported text formats by looking at the length.
+///
+/// ## Arguments
+/// * `value` - A custom base 64 (22), checked custom base 64 (23),
+///   hexadecimal (32), standard uuid (36), braced uuid (38), or `urn:uuid:`
+///   URN (45) encoded byte slice.
+pub(crate) fn decode(value: &[u8]) -> Result<u128, U64Error> {
+    decode_with_mode(value, ParseMode::Lenient)
+}
+
+/// Decodes any of the supported text formats by looking at the length using
+/// the given [ParseMode].
+///
+/// ## Arguments
+/// * `value` - A custom base 64 (22), checked custom base 64 (23),
+///   hexadecimal (32), standard uuid (36), braced uuid (38), or `urn:uuid:`
+///   URN (45) encoded byte slice.
+/// * `mode` - How strictly the input is checked.
+pub(crate) fn decode_with_mode(
+    value: &[u8],
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    match (value.len(), mode) {
+        (22, ParseMode::Lenient) => decode_base64(value[..22].try_into()?),
+        (22, ParseMode::Strict) => {
+            let value = value[..22].try_into()?;
+            check_fill_bits(value, &BASE64_DECODE, Format::Base64)?;
+            decode_base64(value)
+        }
+        (23, _) => decode_base64_checked(value[..23].try_into()?, mode),
+        (32, _) => decode_hex_with_mode(value[..32].try_into()?, mode),
+        (36, _) => decode_hyphenated(value, 0, Format::Uuid, mode),
+        (38, _) => decode_braced(value[..38].try_into()?, mode),
+        (45, _) => decode_urn(value[..45].try_into()?, mode),
+        (n, _) => Err(U64Error::InvalidStrLength(n)),
+    }
+}
+
+/// Decodes the custom base 64 (22), hexadecimal (32), or standard uuid (36)
+/// formats in a const context.
+///
+/// Gives the same results as [decode] for those lengths.
+pub(crate) const fn decode_const(value: &[u8]) -> Result<u128, U64Error> {
+    match value.len() {
+        22 => decode_base64(chunk(value)),
+        32 => decode_hex(chunk(value)),
+        36 => decode_uuid(chunk(value)),
+        n => Err(U64Error::InvalidStrLength(n)),
+    }
+}
+
+/// Views the start of the slice as an array once the length was checked.
+const fn chunk<const N: usize>(value: &[u8]) -> &[u8; N] {
+    match value.first_chunk() {
+        Some(v) => v,
+        None => panic!("the slice is shorter than the array"),
+    }
+}
+
+/// Decodes the value as the given text format without looking at the length.
+///
+/// [Format::Crockford] expects a check symbol when there are 27 symbols
+/// besides any hyphens.
+///
+/// __NOTE:__ _[Format::Bytes] and [Format::Custom] need information only
+/// the caller has and return [U64Error::UnsupportedFormat]._
+pub(crate) fn decode_as(
+    value: &[u8],
+    format: Format,
+) -> Result<u128, U64Error> {
+    let mode = ParseMode::Lenient;
+    match format {
+        Format::Base64 => decode_base64(fixed(value, format)?),
+        Format::Base64Checked => {
+            decode_base64_checked(fixed(value, format)?, mode)
+        }
+        Format::Base64Sortable => decode_base64_sortable(fixed(value, format)?),
+        Format::Hex => decode_hex(fixed(value, format)?),
+        Format::Uuid => decode_uuid(fixed(value, format)?),
+        Format::Urn => decode_urn(fixed(value, format)?, mode),
+        Format::Braced => decode_braced(fixed(value, format)?, mode),
+        Format::Crockford => {
+            let symbols = value.iter().filter(|b| **b != b'-').count();
+            decode_crockford(value, symbols == 27)
+        }
+        Format::Bytes | Format::Custom => {
+            Err(U64Error::UnsupportedFormat(format))
+        }
+    }
+}
+
+/// Turns the slice into a fixed size array reporting the length on failure.
+pub(crate) fn fixed<const N: usize>(
+    value: &[u8],
+    format: Format,
+) -> Result<&[u8; N], U64Error> {
+    value.try_into().map_err(|_| U64Error::invalid_length(format, value.len()))
+}
+
+/// The custom base 64 alphabet of [Alphabet::BASE64].
+///
+/// [Alphabet::BASE64]: crate::Alphabet::BASE64
+pub(crate) const BASE64_ALPHABET: [u8; 64] =
+    *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+/// The sortable custom base 64 alphabet of [Alphabet::BASE64_SORTABLE].
+///
+/// [Alphabet::BASE64_SORTABLE]: crate::Alphabet::BASE64_SORTABLE
+pub(crate) const BASE64_SORTABLE_ALPHABET: [u8; 64] =
+    *b"-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz";
+/// Douglas Crockford's base 32 symbols.
+pub(crate) const CROCKFORD_ALPHABET: [u8; 32] =
+    *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// The Crockford base 32 symbols followed by the 5 extra check symbols.
+const CROCKFORD_CHECK_ALPHABET: [u8; 37] =
+    *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+/// Marks bytes in a decode table that are not part of the alphabet.
+pub(crate) const INVALID: u8 = 0xff;
+/// Reverse lookup table for [BASE64_ALPHABET].
+pub(crate) static BASE64_DECODE: [u8; 256] = decode_table(&BASE64_ALPHABET);
+/// Reverse lookup table for [BASE64_SORTABLE_ALPHABET].
+static BASE64_SORTABLE_DECODE: [u8; 256] =
+    decode_table(&BASE64_SORTABLE_ALPHABET);
+
+/// [Alphabet::BASE32_CROCKFORD] that also decodes `I` and `L` as `1` and `O`
+/// as `0`.
+pub(crate) const CROCKFORD: Alphabet = Alphabet::BASE32_CROCKFORD
+    .alias(b'I', b'1')
+    .alias(b'L', b'1')
+    .alias(b'O', b'0');
+/// The check symbols of [CROCKFORD_CHECK_ALPHABET] with the same aliases as
+/// [CROCKFORD].
+pub(crate) const CROCKFORD_CHECK: Alphabet =
+    Alphabet::new(&CROCKFORD_CHECK_ALPHABET)
+        .case_insensitive()
+        .alias(b'I', b'1')
+        .alias(b'L', b'1')
+        .alias(b'O', b'0');
+
+/// Builds a reverse lookup table from byte to digit value for an alphabet.
+pub(crate) const fn decode_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Encodes the value with one of the custom base 64 alphabets.
+///
+/// The 128 bits of the value are padded with 4 leading zero bits to give 22
+/// characters of 6 bits each.
+///
+/// ## Arguments
+/// * `value` - The value to encode.
+/// * `alphabet` - Either [BASE64_ALPHABET] or [BASE64_SORTABLE_ALPHABET].
+/// * `buf` - Where the encoded characters are written.
+pub(crate) fn encode_base64<'a>(
+    value: u128,
+    alphabet: &[u8; 64],
+    buf: &'a mut [u8; 22],
+) -> &'a str {
+    for (i, b) in buf.iter_mut().enumerate() {
+        let shift = 126 - 6 * i;
+        *b = alphabet[(value >> shift) as usize & 0x3f];
+    }
+    core::str::from_utf8(buf).expect("Base 64 alphabets only contain ASCII")
+}
+
+/// Encodes the value with the custom base 64 alphabet followed by a check
+/// character.
+///
+/// The check character is the Luhn mod 64 check digit of the 22 characters.
+pub(crate) fn encode_base64_checked(value: u128, buf: &mut [u8; 23]) -> &str {
+    let (chars, check) = buf.split_at_mut(22);
+    encode_base64(value, &BASE64_ALPHABET, chars.try_into().expect("22 bytes"));
+    let digits = (0..22).map(|i| (value >> (126 - 6 * i)) as u8 & 0x3f);
+    check[0] = BASE64_ALPHABET[luhn_mod64(digits) as usize];
+    core::str::from_utf8(buf).expect("Base 64 alphabets only contain ASCII")
+}
+
+/// Calculates the Luhn mod 64 check digit of the base 64 digit values.
+///
+/// Every single character typo changes the check digit as does swapping any
+/// two neighbouring characters except `A` and `_`.
+fn luhn_mod64<I: DoubleEndedIterator<Item = u8>>(digits: I) -> u8 {
+    let mut sum = 0u32;
+    for (i, digit) in digits.rev().enumerate() {
+        let mut addend = digit as u32;
+        // Starting with the right most digit every other one is doubled.
+        if i % 2 == 0 {
+            addend *= 2;
+            addend = addend / 64 + addend % 64;
+        }
+        sum += addend;
+    }
+    ((64 - sum % 64) % 64) as u8
+}
+
+/// The digits used for the lower case hexadecimal encodings.
+pub(crate) const HEX_DIGITS: [u8; 16] = *b"0123456789abcdef";
+/// The digits used for the upper case hexadecimal encodings.
+pub(crate) const HEX_DIGITS_UPPER: [u8; 16] = *b"0123456789ABCDEF";
+/// The prefix of the `urn:uuid:` URN.
+const URN_PREFIX: [u8; 9] = *b"urn:uuid:";
+
+/// Encodes the value as 32 lower case hexadecimal digits.
+pub(crate) fn encode_hex(value: u128, buf: &mut [u8; 32]) -> &str {
+    encode_hex_with(value, &HEX_DIGITS, buf)
+}
+
+/// Encodes the value as 32 hexadecimal digits.
+///
+/// ## Arguments
+/// * `value` - The value to encode.
+/// * `digits` - Either [HEX_DIGITS] or [HEX_DIGITS_UPPER].
+/// * `buf` - Where the encoded characters are written.
+pub(crate) fn encode_hex_with<'a>(
+    value: u128,
+    digits: &[u8; 16],
+    buf: &'a mut [u8; 32],
+) -> &'a str {
+    for (i, b) in buf.iter_mut().enumerate() {
+        let shift = 124 - 4 * i;
+        *b = digits[(value >> shift) as usize & 0xf];
+    }
+    core::str::from_utf8(buf).expect("Hexadecimal digits are ASCII")
+}
+
+/// Encodes the value as a lower case standard hyphenated uuid.
+pub(crate) fn encode_uuid(value: u128, buf: &mut [u8; 36]) -> &str {
+    encode_uuid_with(value, &HEX_DIGITS, buf)
+}
+
+/// Encodes the value as a standard hyphenated uuid.
+///
+/// ## Arguments
+/// * `value` - The value to encode.
+/// * `digits` - Either [HEX_DIGITS] or [HEX_DIGITS_UPPER].
+/// * `buf` - Where the encoded characters are written.
+pub(crate) fn encode_uuid_with<'a>(
+    value: u128,
+    digits: &[u8; 16],
+    buf: &'a mut [u8; 36],
+) -> &'a str {
+    let mut hex = [0u8; 32];
+    encode_hex_with(value, digits, &mut hex);
+    let mut pos = 0;
+    for (i, b) in buf.iter_mut().enumerate() {
+        if HYPHENS.contains(&i) {
+            *b = b'-';
+        } else {
+            *b = hex[pos];
+            pos += 1;
+        }
+    }
+    core::str::from_utf8(buf).expect("Hexadecimal digits are ASCII")
+}
+
+/// Encodes the value as an `urn:uuid:` URN.
+///
+/// The prefix is always lower case and `digits` is only used for the uuid.
+pub(crate) fn encode_urn<'a>(
+    value: u128,
+    digits: &[u8; 16],
+    buf: &'a mut [u8; 45],
+) -> &'a str {
+    let (prefix, uuid) = buf.split_at_mut(9);
+    prefix.copy_from_slice(&URN_PREFIX);
+    encode_uuid_with(value, digits, uuid.try_into().expect("36 bytes"));
+    core::str::from_utf8(buf).expect("URN is ASCII")
+}
+
+/// Encodes the value as a Microsoft style `{...}` braced uuid.
+pub(crate) fn encode_braced<'a>(
+    value: u128,
+    digits: &[u8; 16],
+    buf: &'a mut [u8; 38],
+) -> &'a str {
+    buf[0] = b'{';
+    buf[37] = b'}';
+    let uuid = (&mut buf[1..37]).try_into().expect("36 bytes");
+    encode_uuid_with(value, digits, uuid);
+    core::str::from_utf8(buf).expect("Braced uuid is ASCII")
+}
+
+/// Encodes the value with Crockford's base 32 symbols.
+///
+/// The 128 bits of the value are padded with 2 leading zero bits to give 26
+/// characters of 5 bits each.
+pub(crate) fn encode_crockford(value: u128, buf: &mut [u8; 26]) -> &str {
+    let mut symbols = [0u8; Alphabet::MAX_WIDTH];
+    let encoded = Alphabet::BASE32_CROCKFORD.encode(value, &mut symbols);
+    buf.copy_from_slice(encoded.as_bytes());
+    core::str::from_utf8(buf).expect("Crockford symbols are ASCII")
+}
+
+/// Encodes the value with Crockford's base 32 symbols followed by the check
+/// symbol for the value modulo 37.
+pub(crate) fn encode_crockford_checked(
+    value: u128,
+    buf: &mut [u8; 27],
+) -> &str {
+    let (symbols, check) = buf.split_at_mut(26);
+    encode_crockford(value, symbols.try_into().expect("26 bytes"));
+    check[0] = CROCKFORD_CHECK_ALPHABET[(value % 37) as usize];
+    core::str::from_utf8(buf).expect("Crockford symbols are ASCII")
+}
+
+/// Decodes Crockford's base 32 encoding.
+///
+/// Hyphens are ignored anywhere in the input and the aliases of [CROCKFORD]
+/// are accepted. Offsets in errors are into the input including any hyphens.
+///
+/// ## Arguments
+/// * `value` - 26 symbols plus a check symbol when `checked`.
+/// * `checked` - When the last symbol is a check symbol that must match.
+pub(crate) fn decode_crockford(
+    value: &[u8],
+    checked: bool,
+) -> Result<u128, U64Error> {
+    let expected = if checked { 27 } else { 26 };
+    // The symbols without hyphens and where each one was in the input.
+    let mut symbols = [0u8; 27];
+    let mut offsets = [0usize; 27];
+    let mut count = 0;
+    for (i, b) in value.iter().enumerate() {
+        if *b == b'-' {
+            continue;
+        }
+        if count < expected {
+            symbols[count] = *b;
+            offsets[count] = i;
+        }
+        count += 1;
+    }
+    if count != expected {
+        return Err(U64Error::invalid_length(Format::Crockford, value.len()));
+    }
+    let result = CROCKFORD.decode(&symbols[..26]).map_err(|e| {
+        let e = e.parse_error().expect("the engine only reports parse errors");
+        let e = match e.kind() {
+            // Only the first symbol has more bits than are left to fill.
+            ParseErrorKind::Overflow => {
+                ParseError::new(Format::Crockford, offsets[0], e.kind())
+            }
+            _ => ParseError::invalid_character(
+                Format::Crockford,
+                value,
+                offsets[e.offset()],
+            ),
+        };
+        U64Error::InvalidEncodedString(e)
+    })?;
+    if checked {
+        let i = offsets[26];
+        match CROCKFORD_CHECK.digit(symbols[26]) {
+            Some(digit) if digit as u128 == result % 37 => {}
+            Some(_) => {
+                let kind = ParseErrorKind::ChecksumMismatch;
+                let e = ParseError::new(Format::Crockford, i, kind);
+                return Err(U64Error::ChecksumMismatch(e));
+            }
+            None => {
+                let e =
+                    ParseError::invalid_character(Format::Crockford, value, i);
+                return Err(U64Error::InvalidEncodedString(e));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Swaps between the Microsoft GUID byte layout and big-endian bytes.
+///
+/// The first three fields of a GUID are little-endian while the rest are in
+/// the same order so the same swap works in both directions.
+pub(crate) const fn swap_guid_bytes(bytes: [u8; 16]) -> [u8; 16] {
+    let b = bytes;
+    [
+        b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6], b[8], b[9], b[10],
+        b[11], b[12], b[13], b[14], b[15],
+    ]
+}
+
+/// Decodes the custom base 64 encoding.
+pub(crate) const fn decode_base64(value: &[u8; 22]) -> Result<u128, U64Error> {
+    decode_base64_with(value, &BASE64_DECODE, Format::Base64)
+}
+
+/// Decodes the sortable custom base 64 encoding.
+pub(crate) fn decode_base64_sortable(
+    value: &[u8; 22],
+) -> Result<u128, U64Error> {
+    decode_base64_with(value, &BASE64_SORTABLE_DECODE, Format::Base64Sortable)
+}
+
+/// Decodes the custom base 64 encoding followed by a check character.
+///
+/// Invalid characters are reported before a wrong check character. The fill
+/// bits are only checked in [ParseMode::Strict].
+pub(crate) fn decode_base64_checked(
+    value: &[u8; 23],
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    let format = Format::Base64Checked;
+    let mut digits = [0u8; 23];
+    for (i, b) in value.iter().enumerate() {
+        digits[i] = BASE64_DECODE[*b as usize];
+        if digits[i] == INVALID {
+            let e = ParseError::invalid_character(format, value, i);
+            return Err(U64Error::InvalidBase64String(e));
+        }
+    }
+    if luhn_mod64(digits[..22].iter().copied()) != digits[22] {
+        let kind = ParseErrorKind::ChecksumMismatch;
+        let e = ParseError::new(format, 22, kind);
+        return Err(U64Error::ChecksumMismatch(e));
+    }
+    let value = value[..22].try_into()?;
+    if mode == ParseMode::Strict {
+        check_fill_bits(value, &BASE64_DECODE, format)?;
+    }
+    decode_base64_with(value, &BASE64_DECODE, format)
+}
+
+/// Decodes one of the custom base 64 alphabets using its reverse lookup table.
+const fn decode_base64_with(
+    value: &[u8; 22],
+    table: &[u8; 256],
+    format: Format,
+) -> Result<u128, U64Error> {
+    let mut result = 0u128;
+    let mut i = 0;
+    while i < value.len() {
+        let bits = table[value[i] as usize];
+        if bits == INVALID {
+            let e = ParseError::invalid_character(format, value, i);
+            return Err(U64Error::InvalidBase64String(e));
+        }
+        // The 4 fill bits that were add to have 22 chars get shifted out.
+        result = result << 6 | bits as u128;
+        i += 1;
+    }
+    Ok(result)
+}
+
+/// Checks the 4 unused leading bits of a base 64 string are zero.
+///
+/// Anything else can not have come from the encoder.
+fn check_fill_bits(
+    value: &[u8; 22],
+    table: &[u8; 256],
+    format: Format,
+) -> Result<(), U64Error> {
+    match table[value[0] as usize] {
+        INVALID => {
+            let e = ParseError::invalid_character(format, value, 0);
+            Err(U64Error::InvalidBase64String(e))
+        }
+        bits if bits > 3 => {
+            let kind = ParseErrorKind::InvalidFillBits;
+            let e = ParseError::new(format, 0, kind);
+            Err(U64Error::InvalidBase64String(e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the value of a hexadecimal digit in either case.
+pub(crate) const fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes an utf-8 hexadecimal byte array.
+///
+/// __NOTE:__ _A leading `+` is skipped like `u128::from_str_radix` did
+/// when it was used to decode._
+pub(crate) const fn decode_hex(value: &[u8; 32]) -> Result<u128, U64Error> {
+    decode_hex_with_mode(value, ParseMode::Lenient)
+}
+
+/// Decodes an utf-8 hexadecimal byte array using the given [ParseMode].
+///
+/// Only the lenient mode skips a leading `+`.
+const fn decode_hex_with_mode(
+    value: &[u8; 32],
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    let mut result = 0u128;
+    let mut i = 0;
+    if value[0] == b'+' && matches!(mode, ParseMode::Lenient) {
+        i = 1;
+    }
+    while i < value.len() {
+        match hex_value(value[i]) {
+            Some(v) => result = result << 4 | v as u128,
+            None => {
+                let e = ParseError::invalid_character(Format::Hex, value, i);
+                return Err(U64Error::InvalidHexString(e));
+            }
+        }
+        i += 1;
+    }
+    Ok(result)
+}
+
+/// Byte offsets of the hyphens in a standard uuid.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Decodes an utf-8 standard uuid byte array.
+///
+/// the first 4 '-' characters found in the `value` will be removed.
+///
+/// __NOTE:__ _Like [decode_hex] a leading `+` in the remaining 32 characters
+/// is skipped._
+pub(crate) const fn decode_uuid(value: &[u8; 36]) -> Result<u128, U64Error> {
+    decode_hyphenated(value, 0, Format::Uuid, ParseMode::Lenient)
+}
+
+/// Decodes an utf-8 `urn:uuid:` URN byte array.
+///
+/// The prefix is not case sensitive.
+pub(crate) fn decode_urn(
+    value: &[u8; 45],
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    for (i, b) in URN_PREFIX.iter().enumerate() {
+        if !value[i].eq_ignore_ascii_case(b) {
+            let e = ParseError::invalid_character(Format::Urn, value, i);
+            return Err(U64Error::InvalidUuidString(e));
+        }
+    }
+    decode_hyphenated(value, 9, Format::Urn, mode)
+}
+
+/// Decodes an utf-8 Microsoft style `{...}` braced uuid byte array.
+pub(crate) fn decode_braced(
+    value: &[u8; 38],
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    if value[0] != b'{' {
+        let e = ParseError::invalid_character(Format::Braced, value, 0);
+        return Err(U64Error::InvalidUuidString(e));
+    }
+    let result = decode_hyphenated(value, 1, Format::Braced, mode)?;
+    if value[37] != b'}' {
+        let e = ParseError::invalid_character(Format::Braced, value, 37);
+        return Err(U64Error::InvalidUuidString(e));
+    }
+    Ok(result)
+}
+
+/// Decodes the 36 characters of a standard uuid starting at `start`.
+///
+/// Errors report offsets into the whole `input` so the formats that wrap a
+/// standard uuid can share this.
+///
+/// ## Arguments
+/// * `input` - The complete input being parsed.
+/// * `start` - Byte offset of the standard uuid in the input.
+/// * `format` - The format reported in errors.
+/// * `mode` - With [ParseMode::Lenient] the first 4 '-' found are removed
+///   and a leading `+` is skipped. With [ParseMode::Strict] the hyphens must
+///   be at their standard byte offsets.
+const fn decode_hyphenated(
+    input: &[u8],
+    start: usize,
+    format: Format,
+    mode: ParseMode,
+) -> Result<u128, U64Error> {
+    let (_, value) = input.split_at(start);
+    let mut result = 0u128;
+    let mut removed = 0;
+    let mut pos = 0;
+    let mut i = 0;
+    while i < 36 {
+        let b = value[i];
+        let skip = match mode {
+            ParseMode::Lenient if b == b'-' && removed < 4 => {
+                removed += 1;
+                true
+            }
+            ParseMode::Lenient => {
+                if pos == 32 {
+                    // Fewer than 4 hyphens so report the first one missing.
+                    let mut h = 0;
+                    while h < HYPHENS.len() && value[HYPHENS[h]] == b'-' {
+                        h += 1;
+                    }
+                    let offset = if h < HYPHENS.len() { HYPHENS[h] } else { i };
+                    let kind = ParseErrorKind::ExpectedHyphen;
+                    let e = ParseError::new(format, start + offset, kind);
+                    return Err(U64Error::InvalidUuidString(e));
+                }
+                pos += 1;
+                pos == 1 && b == b'+'
+            }
+            ParseMode::Strict if matches!(i, 8 | 13 | 18 | 23) => {
+                if b != b'-' {
+                    let kind = ParseErrorKind::ExpectedHyphen;
+                    let e = ParseError::new(format, start + i, kind);
+                    return Err(U64Error::InvalidUuidString(e));
+                }
+                true
+            }
+            ParseMode::Strict if b == b'-' && i < 23 => {
+                // A hyphen too early so report where it should have been.
+                let mut h = 0;
+                while HYPHENS[h] < i {
+                    h += 1;
+                }
+                let kind = ParseErrorKind::ExpectedHyphen;
+                let e = ParseError::new(format, start + HYPHENS[h], kind);
+                return Err(U64Error::InvalidUuidString(e));
+            }
+            ParseMode::Strict => false,
+        };
+        if !skip {
+            match hex_value(b) {
+                Some(v) => result = result << 4 | v as u128,
+                None => {
+                    let offset = start + i;
+                    let e =
+                        ParseError::invalid_character(format, input, offset);
+                    return Err(U64Error::InvalidUuidString(e));
+                }
+            }
+        }
+        i += 1;
+    }
+    Ok(result)
+}
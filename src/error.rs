@@ -35,57 +35,320 @@
 // SOFTWARE.
 //! A common set of error and result type used in the library.
 
-use std::array::TryFromSliceError;
+use crate::Format;
+use core::{array::TryFromSliceError, fmt};
 use thiserror::Error;
 
 /// Provides a shared set of error types.
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum U64Error {
-    #[error(
-        "The given base64 string contained one or more invalid characters"
+    #[error("The given base64 string is invalid: {0}")]
+    InvalidBase64String(ParseError),
+    #[deprecated(
+        note = "never returned, the variant of the format reports bad digits"
     )]
-    InvalidBase64String,
     #[error("The given binary string contained one or more invalid digits")]
     InvalidBinString,
-    #[error(
-        "The given hexadecimal string contained one or more invalid digits"
-    )]
-    InvalidHexString,
+    #[error("The given hexadecimal string is invalid: {0}")]
+    InvalidHexString(ParseError),
     #[error(transparent)]
     InvalidSliceLength(#[from] TryFromSliceError),
     #[error("Can not convert a string/slice with length of: {0}")]
     InvalidStrLength(usize),
+    #[deprecated(
+        note = "never returned, the variant of the format reports bad UTF-8"
+    )]
     #[error("The given string contained one or more invalid UTF-8 characters")]
     InvalidUtf8String,
-    #[error("The given uuid string contained one or more invalid characters")]
-    InvalidUuidString,
+    #[error("The given uuid string is invalid: {0}")]
+    InvalidUuidString(ParseError),
+    #[error("The version or variant bits do not match the uuid type")]
+    InvalidVersionOrVariant,
+    #[error("The given encoded string is invalid: {0}")]
+    InvalidEncodedString(ParseError),
+    #[error("The check symbol does not match the value: {0}")]
+    ChecksumMismatch(ParseError),
+    #[error(
+        "The input could be more than one format, parse it as one instead"
+    )]
+    AmbiguousFormat,
+    #[error("Can not parse {0} input without more information")]
+    UnsupportedFormat(Format),
+    #[error("The counter ran out of values for the current millisecond")]
+    CounterOverflow,
+}
+
+impl U64Error {
+    /// Construct the error for input of the wrong length for a format.
+    ///
+    /// It uses the same variant as the other failures of the format.
+    pub(crate) const fn invalid_length(format: Format, len: usize) -> Self {
+        let e = ParseError::new(format, len, ParseErrorKind::InvalidLength);
+        match format {
+            Format::Base64 | Format::Base64Checked | Format::Base64Sortable => {
+                Self::InvalidBase64String(e)
+            }
+            Format::Hex => Self::InvalidHexString(e),
+            Format::Uuid | Format::Urn | Format::Braced => {
+                Self::InvalidUuidString(e)
+            }
+            Format::Crockford | Format::Bytes | Format::Custom => {
+                Self::InvalidEncodedString(e)
+            }
+        }
+    }
+    /// Returns the details of the failure when a string could not be parsed.
+    pub fn parse_error(&self) -> Option<&ParseError> {
+        use crate::U64Error::*;
+        match self {
+            InvalidBase64String(e)
+            | InvalidHexString(e)
+            | InvalidUuidString(e)
+            | InvalidEncodedString(e)
+            | ChecksumMismatch(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for U64Error {
+    #[allow(deprecated)]
     fn eq(&self, other: &Self) -> bool {
         use crate::U64Error::*;
         match (self, other) {
-            (InvalidBase64String, InvalidBase64String) => true,
+            (InvalidBase64String(m), InvalidBase64String(n)) => m == n,
             (InvalidBinString, InvalidBinString) => true,
-            (InvalidHexString, InvalidHexString) => true,
+            (InvalidHexString(m), InvalidHexString(n)) => m == n,
             (InvalidSliceLength(_), InvalidSliceLength(_)) => true,
             (InvalidStrLength(m), InvalidStrLength(n)) => m == n,
             (InvalidUtf8String, InvalidUtf8String) => true,
-            (InvalidUuidString, InvalidUuidString) => true,
+            (InvalidUuidString(m), InvalidUuidString(n)) => m == n,
+            (InvalidVersionOrVariant, InvalidVersionOrVariant) => true,
+            (InvalidEncodedString(m), InvalidEncodedString(n)) => m == n,
+            (ChecksumMismatch(m), ChecksumMismatch(n)) => m == n,
+            (AmbiguousFormat, AmbiguousFormat) => true,
+            (UnsupportedFormat(m), UnsupportedFormat(n)) => m == n,
+            (CounterOverflow, CounterOverflow) => true,
             _ => false,
         }
     }
 }
 
-impl From<U64Error> for std::num::ParseIntError {
-    fn from(ue: U64Error) -> Self {
-        ue.into()
+/// Describes where and why a string could not be parsed.
+///
+/// The offset is in bytes from the start of the input given to the parser.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseError {
+    format: Format,
+    offset: usize,
+    kind: ParseErrorKind,
+}
+
+/// The reason a [ParseError] happened.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A character that is not part of the format was found.
+    ///
+    /// Bytes that are not valid UTF-8 are reported as
+    /// [REPLACEMENT_CHARACTER](char::REPLACEMENT_CHARACTER).
+    InvalidCharacter(char),
+    /// A `-` was expected but something else was found.
+    ExpectedHyphen,
+    /// The unused leading bits of a base 64 string were not zero.
+    InvalidFillBits,
+    /// The value no longer fits in 128 bits.
+    Overflow,
+    /// The check symbol does not match the one calculated from the value.
+    ChecksumMismatch,
+    /// The input is too short or too long for the format.
+    ///
+    /// The offset is the length of the input.
+    InvalidLength,
+}
+
+impl ParseError {
+    /// Construct a new instance.
+    ///
+    /// ## Arguments
+    /// * `format` - The format that was being parsed.
+    /// * `offset` - Byte offset of the problem in the input.
+    /// * `kind` - What was wrong at the offset.
+    pub const fn new(
+        format: Format,
+        offset: usize,
+        kind: ParseErrorKind,
+    ) -> Self {
+        Self { format, offset, kind }
+    }
+    /// Construct an instance for the invalid character at `offset`.
+    ///
+    /// The character is decoded from the input so multi-byte UTF-8 characters
+    /// are reported whole.
+    pub(crate) const fn invalid_character(
+        format: Format,
+        input: &[u8],
+        offset: usize,
+    ) -> Self {
+        let (_, bytes) = input.split_at(offset);
+        let found = first_char(bytes);
+        Self::new(format, offset, ParseErrorKind::InvalidCharacter(found))
+    }
+    /// The format that was being parsed.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+    /// Byte offset of the problem in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// What was wrong at the offset.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+    /// A short human-readable suggestion on how to fix the input.
+    pub fn hint(&self) -> &'static str {
+        match (self.kind, self.format) {
+            (ParseErrorKind::ExpectedHyphen, Format::Urn) => {
+                "hyphens go at byte offsets 17, 22, 27 and 32"
+            }
+            (ParseErrorKind::ExpectedHyphen, Format::Braced) => {
+                "hyphens go at byte offsets 9, 14, 19 and 24"
+            }
+            (ParseErrorKind::ExpectedHyphen, _) => {
+                "hyphens go at byte offsets 8, 13, 18 and 23"
+            }
+            (ParseErrorKind::InvalidFillBits, Format::Base64Sortable) => {
+                "the first character must be one of '-', '0', '1' or '2'"
+            }
+            (ParseErrorKind::InvalidFillBits, _) => {
+                "the first character must be one of 'A', 'B', 'C' or 'D'"
+            }
+            (ParseErrorKind::Overflow, _) => {
+                "the encoded value is larger than 128 bits"
+            }
+            (ParseErrorKind::ChecksumMismatch, _) => {
+                "a symbol was probably mistyped or two were swapped"
+            }
+            (ParseErrorKind::InvalidLength, Format::Base64) => {
+                "base64 must be exactly 22 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Base64Checked) => {
+                "checked base64 must be exactly 23 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Base64Sortable) => {
+                "sortable base64 must be exactly 22 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Hex) => {
+                "hexadecimal must be exactly 32 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Uuid) => {
+                "uuid must be exactly 36 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Urn) => {
+                "urn must be exactly 45 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Braced) => {
+                "braced uuid must be exactly 38 characters long"
+            }
+            (ParseErrorKind::InvalidLength, Format::Crockford) => {
+                "Crockford base32 needs 26 symbols or 27 with a check symbol"
+            }
+            (ParseErrorKind::InvalidLength, Format::Custom) => {
+                "the input must be as long as the width of the alphabet"
+            }
+            (_, Format::Base64) => "base64 uses A-Z, a-z, 0-9, '-' and '_'",
+            (_, Format::Base64Checked) => {
+                "checked base64 uses A-Z, a-z, 0-9, '-' and '_'"
+            }
+            (_, Format::Base64Sortable) => {
+                "sortable base64 uses '-', 0-9, A-Z, '_' and a-z"
+            }
+            (_, Format::Hex) => "hexadecimal uses 0-9, a-f and A-F",
+            (_, Format::Uuid) => {
+                "uuid uses hexadecimal digits in 8-4-4-4-12 groups split by '-'"
+            }
+            (_, Format::Urn) => "urn uses 'urn:uuid:' followed by a uuid",
+            (_, Format::Braced) => {
+                "braced uuid uses a uuid between '{' and '}'"
+            }
+            (_, Format::Crockford) => {
+                "Crockford base32 uses 0-9, '-' and the letters A-Z except U"
+            }
+            (_, Format::Bytes) => "raw bytes must be exactly 16 long",
+            (_, Format::Custom) => "the character is not part of the alphabet",
+        }
+    }
+}
+
+/// Decodes the UTF-8 character at the start of the bytes.
+///
+/// Gives [REPLACEMENT_CHARACTER](char::REPLACEMENT_CHARACTER) when the bytes
+/// do not start with a valid character.
+const fn first_char(bytes: &[u8]) -> char {
+    let len = match bytes.first() {
+        Some(b) if *b >= 0xf0 => 4,
+        Some(b) if *b >= 0xe0 => 3,
+        Some(b) if *b >= 0xc0 => 2,
+        _ => 1,
+    };
+    if bytes.len() < len {
+        return char::REPLACEMENT_CHARACTER;
+    }
+    let (bytes, _) = bytes.split_at(len);
+    if core::str::from_utf8(bytes).is_err() {
+        return char::REPLACEMENT_CHARACTER;
+    }
+    // Only the bits after the leading ones of the first byte are used.
+    let mut c = if len == 1 { bytes[0] } else { bytes[0] & 0x7f >> len } as u32;
+    let mut i = 1;
+    while i < len {
+        c = c << 6 | (bytes[i] & 0x3f) as u32;
+        i += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
     }
 }
 
-impl From<U64Error> for std::str::Utf8Error {
-    fn from(ue: U64Error) -> Self {
-        ue.into()
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidCharacter(c) => write!(
+                f,
+                "invalid character {:?} at byte offset {} in {} input",
+                c, self.offset, self.format
+            )?,
+            ParseErrorKind::ExpectedHyphen => write!(
+                f,
+                "expected '-' at byte offset {} in {} input",
+                self.offset, self.format
+            )?,
+            ParseErrorKind::InvalidFillBits => write!(
+                f,
+                "unused bits are set at byte offset {} in {} input",
+                self.offset, self.format
+            )?,
+            ParseErrorKind::Overflow => write!(
+                f,
+                "value overflows at byte offset {} in {} input",
+                self.offset, self.format
+            )?,
+            ParseErrorKind::ChecksumMismatch => write!(
+                f,
+                "wrong check symbol at byte offset {} in {} input",
+                self.offset, self.format
+            )?,
+            ParseErrorKind::InvalidLength => write!(
+                f,
+                "wrong length of {} bytes for {} input",
+                self.offset, self.format
+            )?,
+        }
+        write!(f, " ({})", self.hint())
     }
 }
+
+impl core::error::Error for ParseError {}
@@ -0,0 +1,165 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! The text formats understood by the parsers and how strictly they are
+//! checked.
+
+use crate::codec::{
+    hex_value, BASE64_DECODE, CROCKFORD, CROCKFORD_CHECK, INVALID,
+};
+use core::fmt;
+
+/// Identifies one of the formats a UUID can be parsed from.
+///
+/// Used by [ParseError](crate::ParseError) to report which decoder was
+/// attempted when parsing failed and by
+/// [Uuid4::parse_as](crate::Uuid4::parse_as) to pick the decoder up front.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+    /// The 22 character custom base 64 encoding.
+    Base64,
+    /// The 23 character custom base 64 encoding ending with a check
+    /// character.
+    Base64Checked,
+    /// The 22 character sortable custom base 64 encoding.
+    Base64Sortable,
+    /// 32 hexadecimal digits without hyphens.
+    Hex,
+    /// The standard 36 character hyphenated uuid.
+    Uuid,
+    /// The 45 character `urn:uuid:` URN.
+    Urn,
+    /// The 38 character Microsoft style `{...}` braced uuid.
+    Braced,
+    /// Crockford's 26 character base 32 with an optional check symbol.
+    Crockford,
+    /// 16 raw bytes in the byte order the type uses for `From<&[u8; 16]>`.
+    Bytes,
+    /// Any [Alphabet](crate::Alphabet) used with
+    /// [Uuid::encode](crate::Uuid::encode).
+    Custom,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Format::Base64 => "base64",
+            Format::Base64Checked => "checked base64",
+            Format::Base64Sortable => "sortable base64",
+            Format::Hex => "hexadecimal",
+            Format::Uuid => "uuid",
+            Format::Urn => "urn",
+            Format::Braced => "braced uuid",
+            Format::Crockford => "Crockford base32",
+            Format::Bytes => "raw bytes",
+            Format::Custom => "custom alphabet",
+        })
+    }
+}
+
+/// Guesses the format of the input from its length and content.
+///
+/// Returns `None` when the input does not look like any of the formats or
+/// when it could be more than one. 16 bytes are only reported as
+/// [Format::Bytes] when at least one of them is not printable ASCII since a
+/// 16 character string is much more likely to be a mistake. Crockford base 32
+/// is only recognized without hyphens.
+///
+/// __NOTE:__ _Both custom base 64 alphabets use the same characters so 22
+/// characters are always reported as [Format::Base64]. Use
+/// [parse_as](crate::Uuid4::parse_as) with [Format::Base64Sortable] for the
+/// sortable encoding._
+///
+/// ## Arguments
+/// * `input` - The string or bytes to look at.
+pub fn detect_format<T: AsRef<[u8]> + ?Sized>(input: &T) -> Option<Format> {
+    let input = input.as_ref();
+    let base64 =
+        |b: &[u8]| b.iter().all(|c| BASE64_DECODE[*c as usize] != INVALID);
+    let uuid =
+        |b: &[u8]| b.iter().all(|c| *c == b'-' || hex_value(*c).is_some());
+    let crockford = |b: &[u8]| b.iter().all(|c| CROCKFORD.digit(*c).is_some());
+    match input.len() {
+        16 if input.iter().all(|b| b.is_ascii_graphic() || *b == b' ') => None,
+        16 => Some(Format::Bytes),
+        22 => base64(input).then_some(Format::Base64),
+        23 => base64(input).then_some(Format::Base64Checked),
+        26 => crockford(input).then_some(Format::Crockford),
+        27 => {
+            let check = CROCKFORD_CHECK.digit(input[26]).is_some();
+            (check && crockford(&input[..26])).then_some(Format::Crockford)
+        }
+        32 => {
+            let digits = input.strip_prefix(b"+").unwrap_or(input);
+            digits
+                .iter()
+                .all(|c| hex_value(*c).is_some())
+                .then_some(Format::Hex)
+        }
+        36 => uuid(input).then_some(Format::Uuid),
+        38 => {
+            let braced = input[0] == b'{' && input[37] == b'}';
+            (braced && uuid(&input[1..37])).then_some(Format::Braced)
+        }
+        45 => {
+            let prefix = input[..9].eq_ignore_ascii_case(b"urn:uuid:");
+            (prefix && uuid(&input[9..])).then_some(Format::Urn)
+        }
+        _ => None,
+    }
+}
+
+/// Controls how forgiving the parsers are.
+///
+/// See [Uuid4::parse_with_mode](crate::Uuid4::parse_with_mode).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ParseMode {
+    /// Accepts anything the parsers can make sense of.
+    ///
+    /// The first four `-` are removed from the standard uuid wherever they
+    /// are, a leading `+` is skipped in the hexadecimal formats, unused base 64
+    /// fill bits are ignored, and the version and variant bits are silently
+    /// replaced with the ones of the type.
+    #[default]
+    Lenient,
+    /// Rejects any input that the type would not have produced itself.
+    ///
+    /// Hyphens must be at byte offsets 8, 13, 18 and 23, signs and fill bits
+    /// are not allowed, and the version and variant bits must already match
+    /// the type.
+    Strict,
+}
@@ -34,17 +34,60 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-#[cfg(feature = "wasm-bindgen")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "diesel")]
+pub use crate::sql_types::*;
+#[cfg(feature = "v3")]
+pub use crate::uuid3::*;
+#[cfg(feature = "wasm")]
 pub use crate::uuid4gen::*;
-pub use crate::{error::*, uuid4::*};
-use std::collections::HashMap;
+#[cfg(feature = "v5")]
+pub use crate::uuid5::*;
+#[cfg(all(feature = "rand", feature = "std"))]
+pub use crate::uuid7gen::*;
+pub use crate::{
+    adapter::*, alphabet::*, anyuuid::*, error::*, format::*, namespace::*,
+    uuid4::*, uuid4rfc::*, uuid7::*, variant::*,
+};
+
+#[macro_use]
+mod macros;
 
+mod adapter;
+mod alphabet;
+mod anyuuid;
+mod codec;
 mod error;
+mod format;
+mod namespace;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "diesel")]
+mod sql_types;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "v3")]
+mod uuid3;
 mod uuid4;
-#[cfg(feature = "wasm-bindgen")]
+#[cfg(feature = "wasm")]
 mod uuid4gen;
+mod uuid4rfc;
+#[cfg(feature = "v5")]
+mod uuid5;
+mod uuid7;
+#[cfg(all(feature = "rand", feature = "std"))]
+mod uuid7gen;
+mod variant;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
 
 /// Core trait for the library.
 pub trait Uuid {
@@ -58,29 +101,35 @@ pub trait Uuid {
     /// This allows the other trait methods to write the value without knowing
     /// how or where it is actual kept.
     fn set_uuid0(&mut self, v: u128);
+    #[cfg(feature = "alloc")]
     /// Generate a custom base 64 encoded UUID v4 (random).
     fn as_base64(&self) -> String {
-        let mut map = HashMap::with_capacity(64);
-        for (k, v) in Self::BASE64.iter() {
-            map.insert(*k, *v);
-        }
-        let mut binary = String::from("0000");
-        binary += &*format!("{:0>128b}", self.uuid0());
-        let mut result = String::new();
-        for _ in 0..22 {
-            let (bits, remaining) = binary.split_at(6);
-            result.push(
-                *map.get(bits)
-                    .expect("Received unknown bit pattern. Check BASE64"),
-            );
-            binary = remaining.to_string();
-        }
-        result
+        self.encode_base64(&mut [0u8; 22]).into()
     }
+    #[cfg(feature = "alloc")]
+    /// Generate a custom base 64 encoded UUID followed by a check character.
+    ///
+    /// Meant for IDs that people copy by hand. Parsing the 23 characters
+    /// returns [ChecksumMismatch](U64Error::ChecksumMismatch) for any single
+    /// character typo and for nearly all swaps of neighbouring characters.
+    fn as_base64_checked(&self) -> String {
+        self.encode_base64_checked(&mut [0u8; 23]).into()
+    }
+    #[cfg(feature = "alloc")]
+    /// Generate a sortable custom base 64 encoded UUID.
+    ///
+    /// Uses the [BASE64_SORTABLE](Alphabet::BASE64_SORTABLE) alphabet so
+    /// sorting the strings as ASCII text gives the same order as the numeric
+    /// values.
+    fn as_base64_sortable(&self) -> String {
+        self.encode_base64_sortable(&mut [0u8; 22]).into()
+    }
+    #[cfg(feature = "alloc")]
     /// Generate a hexadecimal encoded UUID v4 (random).
     fn as_hex_string(&self) -> String {
-        format!("{:0>32x}", self.uuid0())
+        self.encode_hex_string(&mut [0u8; 32]).into()
     }
+    #[cfg(feature = "alloc")]
     /// Generate a standard UUID v4 (random).
     ///
     /// The original PHP code for the function was found in answer at
@@ -92,19 +141,256 @@ pub trait Uuid {
     /// There have been many other changes since the above code especially with
     /// translation to Rust.
     fn as_uuid(&self) -> String {
-        let mut hex = format!("{:0>32x}", self.uuid0());
-        let mut result = String::new();
-        let chunks = [8usize, 4, 4, 4, 12];
-        for idx in chunks.iter() {
-            let (chunk, remaining) = hex.split_at(*idx);
-            result.push_str(chunk);
-            result.push('-');
-            hex = remaining.to_string();
-        }
-        result.truncate(36);
-        result
-    }
-    /// An array use when decoding/encoding base64.
+        self.encode_uuid(&mut [0u8; 36]).into()
+    }
+    /// Generate an upper case hexadecimal encoded UUID.
+    #[cfg(feature = "alloc")]
+    fn as_hex_string_upper(&self) -> String {
+        self.encode_hex_string_upper(&mut [0u8; 32]).into()
+    }
+    /// Generate an upper case standard UUID.
+    #[cfg(feature = "alloc")]
+    fn as_uuid_upper(&self) -> String {
+        self.encode_uuid_upper(&mut [0u8; 36]).into()
+    }
+    /// Generate an `urn:uuid:` URN.
+    #[cfg(feature = "alloc")]
+    fn as_urn(&self) -> String {
+        self.encode_urn(&mut [0u8; 45]).into()
+    }
+    /// Generate a Microsoft style `{...}` braced UUID.
+    ///
+    /// Use [braced](Uuid::braced) with `{:X}` for the upper case form .NET
+    /// normally produces.
+    #[cfg(feature = "alloc")]
+    fn as_braced(&self) -> String {
+        self.encode_braced(&mut [0u8; 38]).into()
+    }
+    /// Generate a Crockford base 32 encoded UUID.
+    ///
+    /// Uses only upper case letters and digits that are hard to confuse when
+    /// read aloud or typed from printed labels.
+    #[cfg(feature = "alloc")]
+    fn as_crockford(&self) -> String {
+        self.encode_crockford(&mut [0u8; 26]).into()
+    }
+    /// Generate a Crockford base 32 encoded UUID followed by a check symbol.
+    ///
+    /// The check symbol lets
+    /// [from_crockford_checked](Uuid4::from_crockford_checked) detect most
+    /// typing mistakes.
+    #[cfg(feature = "alloc")]
+    fn as_crockford_checked(&self) -> String {
+        self.encode_crockford_checked(&mut [0u8; 27]).into()
+    }
+    /// Encode the value with any [Alphabet].
+    ///
+    /// The string is zero padded to the [width](Alphabet::width) of the
+    /// alphabet. Use [Alphabet::encode] directly to encode without
+    /// allocating.
+    #[cfg(feature = "alloc")]
+    fn encode(&self, alphabet: &Alphabet) -> String {
+        alphabet.encode(self.uuid0(), &mut [0u8; Alphabet::MAX_WIDTH]).into()
+    }
+    /// Write the custom base 64 encoding into a caller provided buffer.
+    ///
+    /// Returns a view of the buffer so no allocation is needed.
+    fn encode_base64<'a>(&self, buf: &'a mut [u8; 22]) -> &'a str {
+        codec::encode_base64(self.uuid0(), &codec::BASE64_ALPHABET, buf)
+    }
+    /// Write the sortable custom base 64 encoding into a caller provided
+    /// buffer.
+    ///
+    /// See [as_base64_sortable](Uuid::as_base64_sortable).
+    fn encode_base64_sortable<'a>(&self, buf: &'a mut [u8; 22]) -> &'a str {
+        let alphabet = &codec::BASE64_SORTABLE_ALPHABET;
+        codec::encode_base64(self.uuid0(), alphabet, buf)
+    }
+    /// Write the checked custom base 64 encoding into a caller provided
+    /// buffer.
+    ///
+    /// See [as_base64_checked](Uuid::as_base64_checked).
+    fn encode_base64_checked<'a>(&self, buf: &'a mut [u8; 23]) -> &'a str {
+        codec::encode_base64_checked(self.uuid0(), buf)
+    }
+    /// Write the hexadecimal encoding into a caller provided buffer.
+    ///
+    /// Returns a view of the buffer so no allocation is needed.
+    fn encode_hex_string<'a>(&self, buf: &'a mut [u8; 32]) -> &'a str {
+        codec::encode_hex(self.uuid0(), buf)
+    }
+    /// Write the standard uuid encoding into a caller provided buffer.
+    ///
+    /// Returns a view of the buffer so no allocation is needed.
+    fn encode_uuid<'a>(&self, buf: &'a mut [u8; 36]) -> &'a str {
+        codec::encode_uuid(self.uuid0(), buf)
+    }
+    /// Write the upper case hexadecimal encoding into a caller provided
+    /// buffer.
+    fn encode_hex_string_upper<'a>(&self, buf: &'a mut [u8; 32]) -> &'a str {
+        codec::encode_hex_with(self.uuid0(), &codec::HEX_DIGITS_UPPER, buf)
+    }
+    /// Write the upper case standard uuid encoding into a caller provided
+    /// buffer.
+    fn encode_uuid_upper<'a>(&self, buf: &'a mut [u8; 36]) -> &'a str {
+        codec::encode_uuid_with(self.uuid0(), &codec::HEX_DIGITS_UPPER, buf)
+    }
+    /// Write the Crockford base 32 encoding into a caller provided buffer.
+    fn encode_crockford<'a>(&self, buf: &'a mut [u8; 26]) -> &'a str {
+        codec::encode_crockford(self.uuid0(), buf)
+    }
+    /// Write the Crockford base 32 encoding with a check symbol into a caller
+    /// provided buffer.
+    fn encode_crockford_checked<'a>(&self, buf: &'a mut [u8; 27]) -> &'a str {
+        codec::encode_crockford_checked(self.uuid0(), buf)
+    }
+    /// Write the `urn:uuid:` URN into a caller provided buffer.
+    fn encode_urn<'a>(&self, buf: &'a mut [u8; 45]) -> &'a str {
+        codec::encode_urn(self.uuid0(), &codec::HEX_DIGITS, buf)
+    }
+    /// Write the `{...}` braced uuid into a caller provided buffer.
+    fn encode_braced<'a>(&self, buf: &'a mut [u8; 38]) -> &'a str {
+        codec::encode_braced(self.uuid0(), &codec::HEX_DIGITS, buf)
+    }
+    /// Returns the value as 16 big-endian bytes.
+    ///
+    /// This is the RFC 9562 network byte order which matches the standard uuid
+    /// string.
+    fn to_bytes_be(&self) -> [u8; 16] {
+        self.uuid0().to_be_bytes()
+    }
+    /// Returns the value as 16 little-endian bytes.
+    fn to_bytes_le(&self) -> [u8; 16] {
+        self.uuid0().to_le_bytes()
+    }
+    /// Returns the value as 16 bytes in the Microsoft GUID layout.
+    ///
+    /// The first three fields are little-endian and the rest big-endian which
+    /// is what SQL Server uses for `uniqueidentifier` columns.
+    fn to_guid_bytes(&self) -> [u8; 16] {
+        codec::swap_guid_bytes(self.to_bytes_be())
+    }
+    /// Returns the bytes with the version and variant in their RFC 9562
+    /// positions.
+    ///
+    /// These are the big-endian bytes for every type except [Uuid4] which
+    /// uses the little-endian bytes of its legacy layout. The version,
+    /// variant, and field methods all read from these bytes.
+    fn to_rfc_bytes(&self) -> [u8; 16] {
+        self.to_bytes_be()
+    }
+    /// The version number from the high 4 bits of `time_hi_and_version`.
+    fn version(&self) -> u8 {
+        self.to_rfc_bytes()[6] >> 4
+    }
+    /// The variant from the high bits of `clock_seq`.
+    fn variant(&self) -> Variant {
+        Variant::from_byte(self.to_rfc_bytes()[8])
+    }
+    /// The `time_low` field from the first 4 bytes.
+    fn time_low(&self) -> u32 {
+        let b = self.to_rfc_bytes();
+        u32::from_be_bytes([b[0], b[1], b[2], b[3]])
+    }
+    /// The `time_mid` field from bytes 4 and 5.
+    fn time_mid(&self) -> u16 {
+        let b = self.to_rfc_bytes();
+        u16::from_be_bytes([b[4], b[5]])
+    }
+    /// The `time_hi_and_version` field from bytes 6 and 7.
+    fn time_hi_and_version(&self) -> u16 {
+        let b = self.to_rfc_bytes();
+        u16::from_be_bytes([b[6], b[7]])
+    }
+    /// The `clock_seq_hi_and_reserved` and `clock_seq_low` fields from bytes
+    /// 8 and 9.
+    ///
+    /// The variant bits are included.
+    fn clock_seq(&self) -> u16 {
+        let b = self.to_rfc_bytes();
+        u16::from_be_bytes([b[8], b[9]])
+    }
+    /// The `node` field from the last 6 bytes.
+    fn node(&self) -> [u8; 6] {
+        let b = self.to_rfc_bytes();
+        [b[10], b[11], b[12], b[13], b[14], b[15]]
+    }
+    /// Checks if all of the bits are zero.
+    fn is_nil(&self) -> bool {
+        self.uuid0() == 0
+    }
+    /// Checks if all of the bits are set.
+    fn is_max(&self) -> bool {
+        self.uuid0() == u128::MAX
+    }
+    /// Write the custom base 64 encoding to any [fmt::Write].
+    fn write_base64<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.encode_base64(&mut [0u8; 22]))
+    }
+    /// Write the hexadecimal encoding to any [fmt::Write].
+    fn write_hex_string<W: fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> fmt::Result {
+        w.write_str(self.encode_hex_string(&mut [0u8; 32]))
+    }
+    /// Write the standard uuid encoding to any [fmt::Write].
+    fn write_uuid<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.encode_uuid(&mut [0u8; 36]))
+    }
+    #[cfg(feature = "std")]
+    /// Write the custom base 64 encoding to any [io::Write].
+    fn write_base64_io<W: io::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()> {
+        w.write_all(self.encode_base64(&mut [0u8; 22]).as_bytes())
+    }
+    #[cfg(feature = "std")]
+    /// Write the hexadecimal encoding to any [io::Write].
+    fn write_hex_string_io<W: io::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()> {
+        w.write_all(self.encode_hex_string(&mut [0u8; 32]).as_bytes())
+    }
+    #[cfg(feature = "std")]
+    /// Write the standard uuid encoding to any [io::Write].
+    fn write_uuid_io<W: io::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()> {
+        w.write_all(self.encode_uuid(&mut [0u8; 36]).as_bytes())
+    }
+    /// Returns an adapter that formats the value with the custom base 64
+    /// encoding.
+    fn base64(&self) -> Base64 {
+        Base64(self.uuid0())
+    }
+    /// Returns an adapter that formats the value as 32 hexadecimal digits.
+    fn simple(&self) -> Simple {
+        Simple(self.uuid0())
+    }
+    /// Returns an adapter that formats the value as a standard hyphenated
+    /// uuid.
+    fn hyphenated(&self) -> Hyphenated {
+        Hyphenated(self.uuid0())
+    }
+    /// Returns an adapter that formats the value as an `urn:uuid:` URN.
+    fn urn(&self) -> Urn {
+        Urn(self.uuid0())
+    }
+    /// Returns an adapter that formats the value as a Microsoft style `{...}`
+    /// braced uuid.
+    fn braced(&self) -> Braced {
+        Braced(self.uuid0())
+    }
+    /// An array that was used when decoding/encoding base64.
+    ///
+    /// __NOTE:__ _This is no longer used. The encoders and decoders always use
+    /// the [BASE64](Alphabet::BASE64) alphabet so overriding this has no
+    /// effect._
+    #[deprecated(note = "no longer used, see `Alphabet::BASE64` instead")]
     const BASE64: [(&'static str, char); 64] = [
         ("000000", 'A'),
         ("000001", 'B'),
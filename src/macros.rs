@@ -0,0 +1,814 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Internal macros used to share the common trait implementations between the
+//! UUID types, plus the exported [uuid64!](crate::uuid64) macro.
+
+/// Parses a [Uuid4](crate::Uuid4) literal at compile time.
+///
+/// Accepts the custom base 64 (22), hexadecimal (32), and standard uuid (36)
+/// formats the same way as `TryFrom<&str>`. A literal that can not be parsed
+/// fails the build instead of panicking at runtime.
+///
+/// ```
+/// use uuid64rs::{uuid64, Uuid4};
+///
+/// const SYSTEM_USER: Uuid4 = uuid64!("AjIiEgHx5GhURDQkFAPz4A");
+/// let expected = Uuid4::from(0x232221201f1e468544434241403f3e00);
+/// assert_eq!(SYSTEM_USER, expected);
+/// assert_eq!(uuid64!("23222120-1f1e-4685-4443-4241403f3e00"), expected);
+/// ```
+///
+/// A typo is a compile error:
+///
+/// ```compile_fail
+/// let id = uuid64rs::uuid64!("23222120-1f1e-4685-4443-4241403f3eZ0");
+/// ```
+#[macro_export]
+macro_rules! uuid64 {
+    ($value:literal) => {{
+        const VALUE: $crate::Uuid4 = match $crate::Uuid4::parse_const($value) {
+            ::core::result::Result::Ok(v) => v,
+            ::core::result::Result::Err(_) => ::core::panic!(
+                "{}",
+                ::core::concat!("invalid uuid64 literal ", stringify!($value))
+            ),
+        };
+        VALUE
+    }};
+}
+
+/// Implements the core trait, formatting, and conversion traits for a UUID
+/// new type.
+///
+/// The type must be a tuple struct wrapping an `u128`.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+/// * `$from_bytes` - Function used to turn 16 raw bytes into an `u128`.
+/// * `$to_bytes` - Function used to turn the `u128` into bytes with the
+///   version and variant in their RFC 9562 positions.
+/// * `$mask` - The bits kept from any value given to the type.
+/// * `$bits` - The version and variant bits set by the type.
+macro_rules! impl_uuid_common {
+    ($t:ty, $from_bytes:path, $to_bytes:path, $mask:expr, $bits:expr) => {
+        impl $crate::Uuid for $t {
+            #[inline]
+            fn uuid0(&self) -> u128 {
+                self.0
+            }
+            #[inline]
+            fn set_uuid0(&mut self, v: u128) {
+                self.0 = v;
+            }
+            fn to_rfc_bytes(&self) -> [u8; 16] {
+                $to_bytes(self.0)
+            }
+        }
+
+        impl $t {
+            /// The nil uuid with all bits zero.
+            ///
+            /// __NOTE:__ _This is not the same as `default()` which has the
+            /// version and variant bits of the type set._
+            pub const NIL: Self = Self(0);
+            /// The max uuid with all bits set.
+            pub const MAX: Self = Self(u128::MAX);
+            /// Construct an instance from the value as is in a const context.
+            ///
+            /// Unlike `From<u128>` the version and variant bits are __not__
+            /// set so the result may not be a valid uuid of the type.
+            pub const fn from_u128_unchecked(v: u128) -> Self {
+                Self(v)
+            }
+            /// The internal value in a const context.
+            ///
+            /// Same as [uuid0](crate::Uuid::uuid0).
+            pub const fn as_u128(&self) -> u128 {
+                self.0
+            }
+            /// The version number in a const context.
+            ///
+            /// Read from the position the type puts its version bits at so it
+            /// only differs from the type's own version for values made with
+            /// [from_u128_unchecked](Self::from_u128_unchecked).
+            pub const fn version(&self) -> u8 {
+                $to_bytes(self.0)[6] >> 4
+            }
+            /// The variant in a const context.
+            ///
+            /// Read from the position the type puts its variant bits at.
+            pub const fn variant(&self) -> $crate::Variant {
+                $crate::Variant::from_byte($to_bytes(self.0)[8])
+            }
+            /// Converts a custom base 64 (22), hexadecimal (32), or standard
+            /// uuid (36) string into a uuid value in a const context.
+            ///
+            /// Accepts the same input as `TryFrom<&str>` for those formats.
+            /// See [uuid64!](crate::uuid64) to check a literal at compile
+            /// time.
+            ///
+            /// ## Arguments
+            /// * `value` - The string to parse.
+            pub const fn parse_const(
+                value: &str,
+            ) -> Result<Self, $crate::U64Error> {
+                match $crate::codec::decode_const(value.as_bytes()) {
+                    Ok(v) => Ok(Self(v & $mask | $bits)),
+                    Err(e) => Err(e),
+                }
+            }
+            /// Converts a sortable custom base 64 string into a uuid value.
+            ///
+            /// See [as_base64_sortable](crate::Uuid::as_base64_sortable).
+            pub fn from_base64_sortable(
+                value: &str,
+            ) -> Result<Self, $crate::U64Error> {
+                let format = $crate::Format::Base64Sortable;
+                let bytes = $crate::codec::fixed(value.as_bytes(), format)?;
+                Ok($crate::codec::decode_base64_sortable(bytes)?.into())
+            }
+            /// Converts a Crockford base 32 string into a uuid value.
+            ///
+            /// Lower case is accepted, `I` and `L` are read as `1`, `O` is
+            /// read as `0`, and hyphens are ignored.
+            ///
+            /// See [as_crockford](crate::Uuid::as_crockford).
+            pub fn from_crockford(
+                value: &str,
+            ) -> Result<Self, $crate::U64Error> {
+                Ok($crate::codec::decode_crockford(value.as_bytes(), false)?
+                    .into())
+            }
+            /// Converts a Crockford base 32 string with a check symbol into a
+            /// uuid value.
+            ///
+            /// Accepts the same input as [from_crockford](Self::from_crockford)
+            /// but returns
+            /// [ChecksumMismatch](crate::U64Error::ChecksumMismatch) when the
+            /// last symbol does not match.
+            ///
+            /// See [as_crockford_checked](crate::Uuid::as_crockford_checked).
+            pub fn from_crockford_checked(
+                value: &str,
+            ) -> Result<Self, $crate::U64Error> {
+                Ok($crate::codec::decode_crockford(value.as_bytes(), true)?
+                    .into())
+            }
+            /// Converts a string made with [encode](crate::Uuid::encode) back
+            /// into a uuid value.
+            ///
+            /// ## Arguments
+            /// * `value` - The string or utf-8 bytes to decode.
+            /// * `alphabet` - The alphabet the value was encoded with.
+            pub fn decode<T: AsRef<[u8]> + ?Sized>(
+                value: &T,
+                alphabet: &$crate::Alphabet,
+            ) -> Result<Self, $crate::U64Error> {
+                Ok(alphabet.decode(value.as_ref())?.into())
+            }
+            /// Construct an instance from 16 big-endian bytes.
+            ///
+            /// This is the RFC 9562 network byte order. The version and
+            /// variant bits are set the same as with `From<u128>`.
+            pub fn from_bytes_be(bytes: [u8; 16]) -> Self {
+                u128::from_be_bytes(bytes).into()
+            }
+            /// Construct an instance from 16 little-endian bytes.
+            ///
+            /// The version and variant bits are set the same as with
+            /// `From<u128>`.
+            pub fn from_bytes_le(bytes: [u8; 16]) -> Self {
+                u128::from_le_bytes(bytes).into()
+            }
+            /// Construct an instance from 16 bytes in the Microsoft GUID
+            /// layout used by SQL Server `uniqueidentifier` columns.
+            ///
+            /// The version and variant bits are set the same as with
+            /// `From<u128>`.
+            pub fn from_guid_bytes(bytes: [u8; 16]) -> Self {
+                Self::from_bytes_be($crate::codec::swap_guid_bytes(bytes))
+            }
+            /// Converts the input using only the decoder for the given
+            /// [Format](crate::Format) instead of guessing it from the length.
+            ///
+            /// [Format::Bytes](crate::Format::Bytes) takes 16 bytes in the
+            /// same byte order as `From<&[u8; 16]>`.
+            /// [Format::Custom](crate::Format::Custom) needs an alphabet so
+            /// use [decode](Self::decode) for it instead. See
+            /// [detect_format](crate::detect_format) to classify unknown
+            /// input first.
+            ///
+            /// ## Arguments
+            /// * `value` - The string or bytes to parse.
+            /// * `format` - The format the input must be in.
+            pub fn parse_as<T: AsRef<[u8]> + ?Sized>(
+                value: &T,
+                format: $crate::Format,
+            ) -> Result<Self, $crate::U64Error> {
+                let value = value.as_ref();
+                match format {
+                    $crate::Format::Bytes => {
+                        let bytes = $crate::codec::fixed(value, format)?;
+                        Ok($from_bytes(*bytes).into())
+                    }
+                    _ => Ok($crate::codec::decode_as(value, format)?.into()),
+                }
+            }
+            /// Converts any of the custom base 64 (22), checked custom base 64
+            /// (23), hexadecimal (32), standard uuid (36), braced uuid (38),
+            /// or URN (45) strings into a uuid value using the given
+            /// [ParseMode](crate::ParseMode).
+            ///
+            /// With [ParseMode::Strict](crate::ParseMode::Strict) the version
+            /// and variant bits must already be the ones used by the type
+            /// instead of being replaced.
+            ///
+            /// ## Arguments
+            /// * `value` - The string or utf-8 bytes to parse.
+            /// * `mode` - How strictly the input is checked.
+            pub fn parse_with_mode<T: AsRef<[u8]> + ?Sized>(
+                value: &T,
+                mode: $crate::ParseMode,
+            ) -> Result<Self, $crate::U64Error> {
+                let v = $crate::codec::decode_with_mode(value.as_ref(), mode)?;
+                match mode {
+                    $crate::ParseMode::Lenient => Ok(v.into()),
+                    $crate::ParseMode::Strict => Self::from_u128_strict(v),
+                }
+            }
+            /// Construct an instance only when the version and variant bits
+            /// of the value already are the ones used by the type.
+            pub(crate) fn from_u128_strict(
+                v: u128,
+            ) -> Result<Self, $crate::U64Error> {
+                let result = Self::from(v);
+                if result.0 != v {
+                    return Err($crate::U64Error::InvalidVersionOrVariant);
+                }
+                Ok(result)
+            }
+            /// Shortcut for [parse_with_mode](Self::parse_with_mode) using
+            /// [ParseMode::Strict](crate::ParseMode::Strict).
+            ///
+            /// Useful to detect corrupted values coming from other systems
+            /// instead of silently fixing them.
+            pub fn parse_strict<T: AsRef<[u8]> + ?Sized>(
+                value: &T,
+            ) -> Result<Self, $crate::U64Error> {
+                Self::parse_with_mode(value, $crate::ParseMode::Strict)
+            }
+        }
+
+        impl ::core::fmt::Display for $t {
+            /// Formats the value with the custom base 64 encoding.
+            ///
+            /// The alternate flag (`{:#}`) switches to the standard hyphenated
+            /// uuid instead.
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                if f.alternate() {
+                    ::core::fmt::Display::fmt(
+                        &$crate::Uuid::hyphenated(self),
+                        f,
+                    )
+                } else {
+                    ::core::fmt::Display::fmt(&$crate::Uuid::base64(self), f)
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $t {
+            type Err = $crate::U64Error;
+
+            /// Accepts all of the same formats as `TryFrom<&str>`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$t as ::core::convert::TryFrom<&str>>::try_from(s)
+            }
+        }
+
+        impl ::core::fmt::Binary for $t {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                let val = self.0;
+                ::core::fmt::Binary::fmt(&val, f)
+            }
+        }
+
+        impl ::core::fmt::LowerHex for $t {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                let val = self.0;
+                ::core::fmt::LowerHex::fmt(&val, f)
+            }
+        }
+
+        impl ::core::fmt::UpperHex for $t {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                let val = self.0;
+                ::core::fmt::UpperHex::fmt(&val, f)
+            }
+        }
+
+        impl From<u128> for $t {
+            /// Keeps the bits of the value except for the version and variant
+            /// bits which are set to the ones of the type.
+            fn from(v: u128) -> Self {
+                Self(v & $mask | $bits)
+            }
+        }
+
+        impl From<&[u8; 16]> for $t {
+            fn from(bytes: &[u8; 16]) -> Self {
+                $from_bytes(*bytes).into()
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts any of the text formats chosen by the length.
+            ///
+            /// Unlike `TryFrom<&[u8]>` a string is never taken as raw bytes.
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Ok($crate::codec::decode(value.as_bytes())?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts raw bytes or any of the text formats chosen by the
+            /// length.
+            ///
+            /// 16 printable ASCII characters are rejected with
+            /// [AmbiguousFormat](crate::U64Error::AmbiguousFormat) instead of
+            /// being taken as raw bytes. Use [parse_as](Self::parse_as) to
+            /// say which format the input is in.
+            fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+                match value.len() {
+                    16 => match $crate::detect_format(value) {
+                        Some(format) => Self::parse_as(value, format),
+                        None => Err($crate::U64Error::AmbiguousFormat),
+                    },
+                    _ => Ok($crate::codec::decode(value)?.into()),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 22]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 custom base 64 byte array into a uuid value.
+            fn try_from(value: &[u8; 22]) -> Result<Self, Self::Error> {
+                Ok($crate::codec::decode_base64(value)?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 23]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 checked custom base 64 byte array into a uuid
+            /// value.
+            ///
+            /// Returns [ChecksumMismatch](crate::U64Error::ChecksumMismatch)
+            /// when the last character does not match the others.
+            fn try_from(value: &[u8; 23]) -> Result<Self, Self::Error> {
+                let mode = $crate::ParseMode::Lenient;
+                Ok($crate::codec::decode_base64_checked(value, mode)?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 32]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 hexadecimal byte array into a uuid value.
+            ///
+            /// __NOTE:__ _This uses [ParseMode::Lenient](crate::ParseMode).
+            /// See [parse_strict](Self::parse_strict) to reject anything the
+            /// type would not have produced._
+            fn try_from(value: &[u8; 32]) -> Result<Self, Self::Error> {
+                Ok($crate::codec::decode_hex(value)?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 36]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 standard uuid byte array into a uuid value.
+            ///
+            /// the first 4 '-' characters found in the `value` will be
+            /// removed.
+            ///
+            /// __NOTE:__ _This uses [ParseMode::Lenient](crate::ParseMode).
+            /// See [parse_strict](Self::parse_strict) to reject anything the
+            /// type would not have produced._
+            fn try_from(value: &[u8; 36]) -> Result<Self, Self::Error> {
+                Ok($crate::codec::decode_uuid(value)?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 38]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 `{...}` braced uuid byte array into a uuid
+            /// value.
+            fn try_from(value: &[u8; 38]) -> Result<Self, Self::Error> {
+                let mode = $crate::ParseMode::Lenient;
+                Ok($crate::codec::decode_braced(value, mode)?.into())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&[u8; 45]> for $t {
+            type Error = $crate::U64Error;
+
+            /// Converts an utf-8 `urn:uuid:` URN byte array into a uuid value.
+            fn try_from(value: &[u8; 45]) -> Result<Self, Self::Error> {
+                let mode = $crate::ParseMode::Lenient;
+                Ok($crate::codec::decode_urn(value, mode)?.into())
+            }
+        }
+    };
+}
+
+/// Implements the random constructors for a UUID new type whose value is all
+/// random except for the version and variant bits.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+#[cfg(feature = "rand")]
+macro_rules! impl_uuid_random {
+    ($t:ty) => {
+        impl $t {
+            #[cfg(feature = "std")]
+            /// Construct a new random instance.
+            ///
+            /// ## Arguments
+            /// * `rng` - Optional random number generator to save startup
+            ///   overhead when generating lots of new UUIDs or other custom
+            ///   needs.
+            pub fn new<'a, TR>(rng: TR) -> Self
+            where
+                TR: Into<Option<&'a mut ::rand::rngs::ThreadRng>>,
+            {
+                match rng.into() {
+                    Some(r) => Self::from_rng(r),
+                    None => Self::from_rng(&mut ::rand::thread_rng()),
+                }
+            }
+            /// Construct a new random instance using any random number
+            /// generator.
+            ///
+            /// Useful with seeded generators for reproducible test fixtures
+            /// or with custom sources. See
+            /// [from_crypto_rng](Self::from_crypto_rng) for call sites where
+            /// the value needs to be unpredictable.
+            ///
+            /// ## Arguments
+            /// * `rng` - The random number generator to use.
+            pub fn from_rng<R: ::rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                let v: u128 = ::rand::Rng::gen(rng);
+                v.into()
+            }
+            /// Construct a new random instance using a cryptographically
+            /// secure random number generator.
+            ///
+            /// ## Arguments
+            /// * `rng` - The random number generator to use.
+            pub fn from_crypto_rng<R>(rng: &mut R) -> Self
+            where
+                R: ::rand::RngCore + ::rand::CryptoRng + ?Sized,
+            {
+                Self::from_rng(rng)
+            }
+        }
+    };
+}
+
+/// Implements the Diesel traits needed to store a UUID new type.
+///
+/// Besides the type's own proxy, which stores the custom base 64 string in a
+/// text column, the shared [UuidBinaryProxy](crate::UuidBinaryProxy) and
+/// [PgUuidProxy](crate::PgUuidProxy) SQL types are supported for the backends
+/// enabled with cargo features.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+/// * `$proxy` - The Diesel SQL type used to store the custom base 64 string.
+#[cfg(feature = "diesel")]
+macro_rules! impl_uuid_diesel {
+    ($t:ty, $proxy:ty) => {
+        impl<DB> ::diesel::deserialize::FromSql<$proxy, DB> for $t
+        where
+            DB: ::diesel::backend::Backend,
+            String:
+                ::diesel::deserialize::FromSql<::diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(
+                bytes: DB::RawValue<'_>,
+            ) -> ::diesel::deserialize::Result<Self> {
+                let text = <String as ::diesel::deserialize::FromSql<
+                    ::diesel::sql_types::Text,
+                    DB,
+                >>::from_sql(bytes)?;
+                Ok(::std::convert::TryFrom::try_from(text.as_bytes())?)
+            }
+        }
+
+        impl<DB> ::diesel::deserialize::FromSql<$crate::UuidBinaryProxy, DB>
+            for $t
+        where
+            DB: ::diesel::backend::Backend,
+            Vec<u8>:
+                ::diesel::deserialize::FromSql<::diesel::sql_types::Binary, DB>,
+        {
+            fn from_sql(
+                bytes: DB::RawValue<'_>,
+            ) -> ::diesel::deserialize::Result<Self> {
+                let bytes = <Vec<u8> as ::diesel::deserialize::FromSql<
+                    ::diesel::sql_types::Binary,
+                    DB,
+                >>::from_sql(bytes)?;
+                let bytes: [u8; 16] =
+                    ::std::convert::TryInto::try_into(&bytes[..])?;
+                Ok(Self::from(&bytes))
+            }
+        }
+
+        #[cfg(feature = "postgres")]
+        impl ::diesel::serialize::ToSql<$proxy, ::diesel::pg::Pg> for $t {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+            ) -> ::diesel::serialize::Result {
+                $crate::Uuid::write_base64_io(self, out)?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "postgres")]
+        impl
+            ::diesel::serialize::ToSql<
+                $crate::UuidBinaryProxy,
+                ::diesel::pg::Pg,
+            > for $t
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+            ) -> ::diesel::serialize::Result {
+                ::std::io::Write::write_all(
+                    out,
+                    &$crate::Uuid::to_rfc_bytes(self),
+                )?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "postgres")]
+        impl
+            ::diesel::deserialize::FromSql<
+                $crate::PgUuidProxy,
+                ::diesel::pg::Pg,
+            > for $t
+        {
+            fn from_sql(
+                value: ::diesel::pg::PgValue<'_>,
+            ) -> ::diesel::deserialize::Result<Self> {
+                let bytes: [u8; 16] =
+                    ::std::convert::TryInto::try_into(value.as_bytes())?;
+                Ok(Self::from(&bytes))
+            }
+        }
+
+        #[cfg(feature = "postgres")]
+        impl ::diesel::serialize::ToSql<$crate::PgUuidProxy, ::diesel::pg::Pg>
+            for $t
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+            ) -> ::diesel::serialize::Result {
+                ::std::io::Write::write_all(
+                    out,
+                    &$crate::Uuid::to_rfc_bytes(self),
+                )?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "mysql")]
+        impl ::diesel::serialize::ToSql<$proxy, ::diesel::mysql::Mysql> for $t {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<
+                    'b,
+                    '_,
+                    ::diesel::mysql::Mysql,
+                >,
+            ) -> ::diesel::serialize::Result {
+                $crate::Uuid::write_base64_io(self, out)?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "mysql")]
+        impl
+            ::diesel::serialize::ToSql<
+                $crate::UuidBinaryProxy,
+                ::diesel::mysql::Mysql,
+            > for $t
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<
+                    'b,
+                    '_,
+                    ::diesel::mysql::Mysql,
+                >,
+            ) -> ::diesel::serialize::Result {
+                ::std::io::Write::write_all(
+                    out,
+                    &$crate::Uuid::to_rfc_bytes(self),
+                )?;
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        impl ::diesel::serialize::ToSql<$proxy, ::diesel::sqlite::Sqlite>
+            for $t
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<
+                    'b,
+                    '_,
+                    ::diesel::sqlite::Sqlite,
+                >,
+            ) -> ::diesel::serialize::Result {
+                out.set_value($crate::Uuid::as_base64(self));
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        impl
+            ::diesel::serialize::ToSql<
+                $crate::UuidBinaryProxy,
+                ::diesel::sqlite::Sqlite,
+            > for $t
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut ::diesel::serialize::Output<
+                    'b,
+                    '_,
+                    ::diesel::sqlite::Sqlite,
+                >,
+            ) -> ::diesel::serialize::Result {
+                out.set_value($crate::Uuid::to_rfc_bytes(self).to_vec());
+                Ok(::diesel::serialize::IsNull::No)
+            }
+        }
+    };
+}
+
+/// Implements the Serde traits for a UUID new type.
+///
+/// Human-readable formats use the custom base 64 string and binary formats use
+/// the 16 raw bytes.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+/// * `$to_bytes` - Function used to turn the `u128` into 16 raw bytes. It
+///   should match the byte order used by `From<&[u8; 16]>`.
+#[cfg(feature = "serde")]
+macro_rules! impl_uuid_serde {
+    ($t:ty, $to_bytes:path) => {
+        impl ::serde::Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                if serializer.is_human_readable() {
+                    let mut buf = [0u8; 22];
+                    let encoded = $crate::Uuid::encode_base64(self, &mut buf);
+                    serializer.serialize_str(encoded)
+                } else {
+                    serializer.serialize_bytes(&$to_bytes(self.0))
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let visitor = $crate::serde::UuidVisitor::new();
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_bytes(visitor)
+                }
+            }
+        }
+    };
+}
+
+/// Implements the conversions to and from the types of other UUID crates.
+///
+/// Each conversion is only included when the cargo feature of the same name
+/// as the other crate is enabled. The 16 bytes of the other types are always
+/// in RFC 9562 order so `$from_bytes` and `$to_bytes` must be the byte order
+/// where the version and variant bits of the type end up in their RFC
+/// positions. Converting into the other types never fails while converting
+/// from them fails with
+/// [InvalidVersionOrVariant](crate::U64Error::InvalidVersionOrVariant) when
+/// the bits do not match the type instead of silently replacing them.
+///
+/// ## Arguments
+/// * `$t` - The UUID type.
+/// * `$from_bytes` - Function used to turn the 16 bytes into an `u128`.
+/// * `$to_bytes` - Function used to turn the `u128` into 16 bytes.
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+macro_rules! impl_uuid_interop {
+    ($t:ty, $from_bytes:path, $to_bytes:path) => {
+        #[cfg(feature = "uuid")]
+        impl From<$t> for ::uuid::Uuid {
+            fn from(value: $t) -> Self {
+                ::uuid::Uuid::from_bytes($to_bytes(value.0))
+            }
+        }
+
+        #[cfg(feature = "uuid")]
+        impl ::core::convert::TryFrom<::uuid::Uuid> for $t {
+            type Error = $crate::U64Error;
+
+            fn try_from(value: ::uuid::Uuid) -> Result<Self, Self::Error> {
+                Self::from_u128_strict($from_bytes(*value.as_bytes()))
+            }
+        }
+
+        #[cfg(feature = "ulid")]
+        impl From<$t> for ::ulid::Ulid {
+            fn from(value: $t) -> Self {
+                ::ulid::Ulid::from_bytes($to_bytes(value.0))
+            }
+        }
+
+        #[cfg(feature = "ulid")]
+        impl ::core::convert::TryFrom<::ulid::Ulid> for $t {
+            type Error = $crate::U64Error;
+
+            /// Fails unless the ULID happens to have the version and variant
+            /// bits of the type. Use `From<&[u8; 16]>` with `ulid.to_bytes()`
+            /// when replacing them is acceptable.
+            fn try_from(value: ::ulid::Ulid) -> Result<Self, Self::Error> {
+                Self::from_u128_strict($from_bytes(value.to_bytes()))
+            }
+        }
+    };
+}
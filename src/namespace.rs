@@ -0,0 +1,112 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Uuid, Uuid4, Uuid4Rfc};
+
+/// Namespace used when generating name-based UUIDs.
+///
+/// The predefined namespaces from RFC 9562 are available as constants and any
+/// existing UUID can also be used as a custom namespace. The namespace is
+/// hashed using the big-endian bytes of its value so it matches the standard
+/// uuid string shown by [as_uuid](Uuid::as_uuid).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Namespace(u128);
+
+impl Namespace {
+    /// Namespace for fully-qualified domain names.
+    pub const DNS: Namespace = Namespace(0x6ba7b8109dad11d180b400c04fd430c8);
+    /// Namespace for URLs.
+    pub const URL: Namespace = Namespace(0x6ba7b8119dad11d180b400c04fd430c8);
+    /// Namespace for ISO object identifiers.
+    pub const OID: Namespace = Namespace(0x6ba7b8129dad11d180b400c04fd430c8);
+    /// Namespace for X.500 distinguished names.
+    pub const X500: Namespace = Namespace(0x6ba7b8149dad11d180b400c04fd430c8);
+    /// Looks up one of the predefined namespaces by name.
+    ///
+    /// ## Arguments
+    /// * `name` - One of `dns`, `url`, `oid`, or `x500`. Case is ignored.
+    pub fn by_name(name: &str) -> Option<Self> {
+        let predefined = [
+            ("dns", Self::DNS),
+            ("url", Self::URL),
+            ("oid", Self::OID),
+            ("x500", Self::X500),
+        ];
+        predefined
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, ns)| *ns)
+    }
+    /// Use any existing UUID as a custom namespace.
+    pub fn from_uuid<U: Uuid>(uuid: &U) -> Self {
+        Self(uuid.uuid0())
+    }
+}
+
+impl Uuid for Namespace {
+    #[inline]
+    fn uuid0(&self) -> u128 {
+        self.0
+    }
+    #[inline]
+    fn set_uuid0(&mut self, v: u128) {
+        self.0 = v;
+    }
+}
+
+impl From<&Uuid4> for Namespace {
+    fn from(uuid: &Uuid4) -> Self {
+        Self::from_uuid(uuid)
+    }
+}
+
+impl From<Uuid4> for Namespace {
+    fn from(uuid: Uuid4) -> Self {
+        Self::from_uuid(&uuid)
+    }
+}
+
+impl From<&Uuid4Rfc> for Namespace {
+    fn from(uuid: &Uuid4Rfc) -> Self {
+        Self::from_uuid(uuid)
+    }
+}
+
+impl From<Uuid4Rfc> for Namespace {
+    fn from(uuid: Uuid4Rfc) -> Self {
+        Self::from_uuid(&uuid)
+    }
+}
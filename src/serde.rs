@@ -0,0 +1,280 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Serde support for the UUID types.
+//!
+//! Human-readable formats like JSON serialize the UUID types as the custom
+//! base 64 string and deserialize from any of the base 64, hexadecimal, or
+//! hyphenated strings as well as from integers the format hands over as
+//! integers. Binary formats use the 16 raw bytes as a byte string.
+//!
+//! Formats that are not self-describing like bincode put a length in front
+//! of every byte string. Use the [fixed] submodule with them to write exactly
+//! 16 bytes, which is also how they stored the `u128` of older versions.
+//!
+//! __NOTE:__ _JSON gives any integer above `u64::MAX` to `deserialize_any` as
+//! a float so the `u128` written by older versions of the library can not be
+//! read back exactly that way. Use the [integer] submodule for those fields._
+//!
+//! The submodules can be used with `#[serde(with = "...")]` to force a
+//! specific string format for a field:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use uuid64rs::Uuid4;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Record {
+//!     #[serde(with = "uuid64rs::serde::hyphenated")]
+//!     id: Uuid4,
+//! }
+//! ```
+
+use crate::{Format, U64Error};
+use ::serde::de::{self, SeqAccess, Visitor};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+
+/// Visitor used by the `Deserialize` implementations of the UUID types.
+pub(crate) struct UuidVisitor<U>(PhantomData<U>);
+
+impl<U> UuidVisitor<U> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, U> Visitor<'de> for UuidVisitor<U>
+where
+    U: for<'a> TryFrom<&'a [u8], Error = U64Error>
+        + for<'a> From<&'a [u8; 16]>
+        + From<u128>,
+{
+    type Value = U;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a uuid string, 16 bytes, or an unsigned integer")
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<U, E> {
+        if v.len() == 16 {
+            // Only raw bytes are allowed to be 16 long.
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        U::try_from(v.as_bytes()).map_err(E::custom)
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<U, E> {
+        if let Ok(bytes) = <&[u8; 16]>::try_from(v) {
+            // Binary formats always give the raw bytes even when printable.
+            return Ok(bytes.into());
+        }
+        U::try_from(v).map_err(E::custom)
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<U, A::Error> {
+        let mut bytes = [0u8; 16];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok((&bytes).into())
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<U, E> {
+        Ok(u128::from(v).into())
+    }
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<U, E> {
+        Ok(v.into())
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<U, E> {
+        let v = u64::try_from(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))?;
+        self.visit_u64(v)
+    }
+}
+
+/// Visitor used by the submodules to only accept one string format.
+struct FormatVisitor<U> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<U, U64Error>,
+}
+
+impl<'de, U> Visitor<'de> for FormatVisitor<U> {
+    type Value = U;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<U, E> {
+        (self.parse)(v).map_err(E::custom)
+    }
+}
+
+/// Parses a string that must be exactly `N` bytes long for the format.
+fn parse_fixed<U, const N: usize>(
+    value: &str,
+    format: Format,
+) -> Result<U, U64Error>
+where
+    U: for<'a> TryFrom<&'a [u8; N], Error = U64Error>,
+{
+    U::try_from(crate::codec::fixed(value.as_bytes(), format)?)
+}
+
+/// Use the custom base 64 string for a field.
+pub mod base64 {
+    use super::*;
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    pub fn serialize<U, S>(uuid: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: Uuid,
+        S: Serializer,
+    {
+        serializer.serialize_str(uuid.encode_base64(&mut [0u8; 22]))
+    }
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where
+        U: for<'a> TryFrom<&'a [u8; 22], Error = U64Error>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FormatVisitor {
+            expecting: "a custom base 64 uuid string",
+            parse: |v| parse_fixed::<U, 22>(v, Format::Base64),
+        })
+    }
+}
+
+/// Use the 32 digit hexadecimal string for a field.
+pub mod simple {
+    use super::*;
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    pub fn serialize<U, S>(uuid: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: Uuid,
+        S: Serializer,
+    {
+        serializer.serialize_str(uuid.encode_hex_string(&mut [0u8; 32]))
+    }
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where
+        U: for<'a> TryFrom<&'a [u8; 32], Error = U64Error>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FormatVisitor {
+            expecting: "a hexadecimal uuid string",
+            parse: |v| parse_fixed::<U, 32>(v, Format::Hex),
+        })
+    }
+}
+
+/// Use the standard hyphenated uuid string for a field.
+pub mod hyphenated {
+    use super::*;
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    pub fn serialize<U, S>(uuid: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: Uuid,
+        S: Serializer,
+    {
+        serializer.serialize_str(uuid.encode_uuid(&mut [0u8; 36]))
+    }
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where
+        U: for<'a> TryFrom<&'a [u8; 36], Error = U64Error>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FormatVisitor {
+            expecting: "a hyphenated uuid string",
+            parse: |v| parse_fixed::<U, 36>(v, Format::Uuid),
+        })
+    }
+}
+
+/// Use a fixed size tuple of the 16 raw bytes for a field.
+///
+/// Meant for binary formats that are not self-describing. Self-describing
+/// ones write this as an array of 16 numbers which takes more space than the
+/// byte string used by default. The bytes are in the same order as
+/// [to_rfc_bytes](crate::Uuid::to_rfc_bytes).
+pub mod fixed {
+    use super::*;
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<U, S>(uuid: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: Uuid,
+        S: Serializer,
+    {
+        uuid.to_rfc_bytes().serialize(serializer)
+    }
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where
+        U: for<'a> TryFrom<&'a [u8], Error = U64Error>
+            + for<'a> From<&'a [u8; 16]>
+            + From<u128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(16, UuidVisitor::new())
+    }
+}
+
+/// Use the `u128` integer value for a field.
+///
+/// Matches what older versions of the library wrote when they derived the
+/// serde traits. The value is requested as an `u128` so JSON keeps numbers
+/// above `u64::MAX` exact.
+pub mod integer {
+    use crate::Uuid;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<U, S>(uuid: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: Uuid,
+        S: Serializer,
+    {
+        serializer.serialize_u128(uuid.uuid0())
+    }
+    pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where
+        U: From<u128>,
+        D: Deserializer<'de>,
+    {
+        u128::deserialize(deserializer).map(U::from)
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Diesel SQL types shared by all of the UUID types.
+//!
+//! Each UUID type also has its own proxy type like
+//! [Uuid4Proxy](crate::Uuid4Proxy) which stores the custom base 64 string in a
+//! text column (`TEXT` in PostgreSQL and SQLite, `CHAR(22)` in MySQL). The
+//! types here let a column use one of the binary representations instead.
+//!
+//! The binary representations use the bytes from
+//! [to_rfc_bytes](crate::Uuid::to_rfc_bytes) so the native PostgreSQL `uuid`
+//! type shows the standard digits. For every type except the legacy
+//! [Uuid4](crate::Uuid4) those are the same digits as
+//! [as_hex_string](crate::Uuid::as_hex_string) and
+//! [as_uuid](crate::Uuid::as_uuid).
+
+use diesel::{query_builder::QueryId, sql_types::SqlType};
+
+/// Stores the 16 raw bytes of the value.
+///
+/// Maps to `BYTEA` in PostgreSQL, `BINARY(16)` in MySQL, and `BLOB` in
+/// SQLite.
+#[derive(Clone, Copy, Debug, Default, QueryId, SqlType)]
+#[cfg_attr(
+    feature = "postgres",
+    diesel(postgres_type(oid = 17, array_oid = 1001))
+)]
+#[cfg_attr(feature = "mysql", diesel(mysql_type(name = "Blob")))]
+#[cfg_attr(feature = "sqlite", diesel(sqlite_type(name = "Binary")))]
+pub struct UuidBinaryProxy;
+
+/// Stores the value using the native PostgreSQL `uuid` type.
+#[derive(Clone, Copy, Debug, Default, QueryId, SqlType)]
+#[cfg_attr(
+    feature = "postgres",
+    diesel(postgres_type(oid = 2950, array_oid = 2951))
+)]
+pub struct PgUuidProxy;
@@ -34,12 +34,411 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod adapter {
+    use crate::*;
+
+    #[test]
+    fn it_should_format_with_each_adapter() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(sut.base64().to_string(), "AjIiEgHx5GhURDQkFAPz4A");
+        assert_eq!(
+            sut.simple().to_string(),
+            "232221201f1e468544434241403f3e00"
+        );
+        assert_eq!(
+            sut.hyphenated().to_string(),
+            "23222120-1f1e-4685-4443-4241403f3e00"
+        );
+        assert_eq!(
+            sut.urn().to_string(),
+            "urn:uuid:23222120-1f1e-4685-4443-4241403f3e00"
+        );
+        assert_eq!(
+            sut.braced().to_string(),
+            "{23222120-1f1e-4685-4443-4241403f3e00}"
+        );
+    }
+    #[test]
+    fn it_should_use_upper_case_digits_with_upper_hex_flag() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(
+            format!("{:X}", sut.simple()),
+            "232221201F1E468544434241403F3E00"
+        );
+        assert_eq!(
+            format!("{:X}", sut.hyphenated()),
+            "23222120-1F1E-4685-4443-4241403F3E00"
+        );
+        assert_eq!(
+            format!("{:X}", sut.urn()),
+            "urn:uuid:23222120-1F1E-4685-4443-4241403F3E00"
+        );
+        assert_eq!(
+            format!("{:x}", sut.braced()),
+            "{23222120-1f1e-4685-4443-4241403f3e00}"
+        );
+        assert_eq!(
+            format!("{:X}", sut.braced()),
+            "{23222120-1F1E-4685-4443-4241403F3E00}"
+        );
+    }
+    #[test]
+    fn it_should_honor_width_and_alignment() {
+        let sut = Uuid4::default();
+        assert_eq!(
+            format!("[{:>24}]", sut.base64()),
+            "[  AAAAAAAAAAgABAAAAAAAAA]"
+        );
+        assert_eq!(
+            format!("[{:-<24}]", sut.base64()),
+            "[AAAAAAAAAAgABAAAAAAAAA--]"
+        );
+    }
+}
+mod alphabet {
+    use crate::*;
+
+    /// All of the predefined alphabets with the expected widths.
+    fn alphabets() -> Vec<(Alphabet, usize)> {
+        vec![
+            (Alphabet::BASE16, 32),
+            (Alphabet::BASE32_CROCKFORD, 26),
+            (Alphabet::BASE36, 25),
+            (Alphabet::BASE58_BITCOIN, 22),
+            (Alphabet::BASE62, 22),
+            (Alphabet::BASE64, 22),
+            (Alphabet::BASE64_SORTABLE, 22),
+        ]
+    }
+
+    #[test]
+    fn it_should_round_trip_edge_values_with_fixed_width() {
+        let inputs = [0u128, 1, u128::MAX, 1 << 127, 0x3f << 122];
+        for (alphabet, width) in alphabets() {
+            assert_eq!(alphabet.width(), width);
+            for input in inputs.iter() {
+                let mut buf = [0u8; Alphabet::MAX_WIDTH];
+                let sut = alphabet.encode(*input, &mut buf);
+                assert_eq!(sut.len(), width);
+                assert_eq!(alphabet.decode(sut.as_bytes()).unwrap(), *input);
+            }
+        }
+    }
+    #[test]
+    fn it_should_match_existing_encodings() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let mut buf = [0u8; Alphabet::MAX_WIDTH];
+        let result = Alphabet::BASE64.encode(sut.uuid0(), &mut buf);
+        assert_eq!(result, sut.encode_base64(&mut [0u8; 22]));
+        let result = Alphabet::BASE16.encode(sut.uuid0(), &mut buf);
+        assert_eq!(result, sut.encode_hex_string(&mut [0u8; 32]));
+        let result = Alphabet::BASE64_SORTABLE.encode(sut.uuid0(), &mut buf);
+        let expected = Uuid4::from_base64_sortable(result).unwrap();
+        assert_eq!(expected, sut);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_encode_and_decode_through_uuid_types() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let result = sut.encode(&Alphabet::BASE58_BITCOIN);
+        assert_eq!(result.len(), 22);
+        let result = Uuid4::decode(&result, &Alphabet::BASE58_BITCOIN);
+        assert_eq!(result.unwrap(), sut);
+        let result = sut.encode(&Alphabet::BASE32_CROCKFORD);
+        assert_eq!(result, "1348GJ07RY8T2M8GT28503YFG0");
+    }
+    #[test]
+    fn it_should_ignore_case_only_when_asked() {
+        let expected = 0x232221201f1e468544434241403f3e00;
+        let input = b"232221201F1E468544434241403F3E00";
+        assert_eq!(Alphabet::BASE16.decode(input).unwrap(), expected);
+        let sut = Alphabet::BASE62.decode(b"0000000000000000000000").unwrap();
+        assert_eq!(sut, 0);
+        let sut = Alphabet::BASE62.decode(b"000000000000000000000a").unwrap();
+        assert_eq!(sut, 36);
+        let sut = Alphabet::BASE62.decode(b"000000000000000000000A").unwrap();
+        assert_eq!(sut, 10);
+    }
+    #[test]
+    fn it_should_return_error_when_decoding_bad_input() {
+        let sut = Alphabet::BASE58_BITCOIN.decode(b"111");
+        let kind = ParseErrorKind::InvalidLength;
+        let e = ParseError::new(Format::Custom, 3, kind);
+        assert_eq!(sut.unwrap_err(), U64Error::InvalidEncodedString(e));
+        let sut = Alphabet::BASE58_BITCOIN.decode(b"111111111111111111111l");
+        let kind = ParseErrorKind::InvalidCharacter('l');
+        let e = ParseError::new(Format::Custom, 21, kind);
+        assert_eq!(sut.unwrap_err(), U64Error::InvalidEncodedString(e));
+        let sut = Alphabet::BASE58_BITCOIN.decode(b"zzzzzzzzzzzzzzzzzzzzzz");
+        let kind = ParseErrorKind::Overflow;
+        let e = ParseError::new(Format::Custom, 21, kind);
+        assert_eq!(sut.unwrap_err(), U64Error::InvalidEncodedString(e));
+    }
+}
+mod anyuuid {
+    use std::convert::{TryFrom, TryInto};
+
+    use crate::*;
+
+    #[test]
+    fn it_should_keep_version_and_variant_bits() {
+        let input = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+        let sut = AnyUuid::try_from(input).unwrap();
+        assert_eq!(sut.version(), 1);
+        assert_eq!(sut.variant(), Variant::Rfc);
+        assert_eq!(sut.encode_uuid(&mut [0u8; 36]), input);
+        let sut: AnyUuid =
+            "00000000-0000-0000-0000-000000000000".parse().unwrap();
+        assert!(sut.is_nil());
+        assert_eq!(sut.version(), 0);
+        let sut = AnyUuid::parse_strict(&"f".repeat(32)).unwrap();
+        assert!(sut.is_max());
+        assert_eq!(sut.variant(), Variant::Future);
+    }
+    #[test]
+    fn it_should_narrow_only_when_bits_match() {
+        let uuid7 = Uuid7::from(0x232221201f1e468544434241403f3e00);
+        let mut buf = [0u8; 22];
+        let sut = AnyUuid::try_from(uuid7.encode_base64(&mut buf)).unwrap();
+        assert_eq!(sut.version(), 7);
+        assert_eq!(sut.try_into(), Ok(uuid7));
+        let result: Result<Uuid4Rfc, _> = sut.try_into();
+        assert_eq!(result, Err(U64Error::InvalidVersionOrVariant));
+        let uuid4 = Uuid4::default();
+        let sut = AnyUuid::from(uuid4);
+        assert_eq!(sut.version(), 4);
+        assert_eq!(Uuid4::try_from(sut), Ok(uuid4));
+        let result = Uuid7::try_from(sut);
+        assert_eq!(result, Err(U64Error::InvalidVersionOrVariant));
+        let sut = AnyUuid::from(uuid4.uuid0());
+        let result = Uuid4::try_from(sut);
+        assert_eq!(result, Err(U64Error::InvalidVersionOrVariant));
+    }
+    #[test]
+    fn it_should_narrow_standard_v4_to_legacy_layout() {
+        let input = "9f8e7d6c-5b4a-4c3d-8e2f-1a0b9c8d7e6f";
+        let sut = AnyUuid::try_from(input).unwrap();
+        assert_eq!(sut.version(), 4);
+        let result = Uuid4::try_from(sut).unwrap();
+        assert_eq!(result, Uuid4::from(Uuid4Rfc::try_from(input).unwrap()));
+        assert_eq!(result.version(), 4);
+        assert_eq!(AnyUuid::from(result), sut);
+    }
+}
+mod codec {
+    use crate::{
+        codec::*, Format, ParseError, ParseErrorKind, ParseMode, U64Error,
+        Uuid, Uuid4,
+    };
+
+    #[test]
+    #[allow(deprecated)]
+    fn it_should_have_alphabets_matching_trait_tables() {
+        for (i, (bits, char)) in Uuid4::BASE64.iter().enumerate() {
+            assert_eq!(usize::from_str_radix(bits, 2).unwrap(), i);
+            assert_eq!(BASE64_ALPHABET[i] as char, *char);
+        }
+    }
+    #[test]
+    fn it_should_round_trip_base64_for_edge_values() {
+        let inputs = [0u128, 1, u128::MAX, 1 << 127, 0x3f << 122];
+        for input in inputs.iter() {
+            let mut buf = [0u8; 22];
+            encode_base64(*input, &BASE64_ALPHABET, &mut buf);
+            assert_eq!(decode_base64(&buf).unwrap(), *input);
+            encode_base64(*input, &BASE64_SORTABLE_ALPHABET, &mut buf);
+            assert_eq!(decode_base64_sortable(&buf).unwrap(), *input);
+        }
+    }
+    #[test]
+    fn it_should_ignore_fill_bits_when_decoding_base64() {
+        let sut = decode_base64(b"_AAAAAAAAAAAAAAAAAAAAA").unwrap();
+        assert_eq!(sut, 3 << 126);
+    }
+    #[test]
+    fn it_should_round_trip_crockford_for_edge_values() {
+        let inputs = [0u128, 1, u128::MAX, 1 << 127, 0x3f << 122];
+        for input in inputs.iter() {
+            let mut buf = [0u8; 26];
+            encode_crockford(*input, &mut buf);
+            assert_eq!(decode_crockford(&buf, false).unwrap(), *input);
+            let mut buf = [0u8; 27];
+            encode_crockford_checked(*input, &mut buf);
+            assert_eq!(decode_crockford(&buf, true).unwrap(), *input);
+        }
+    }
+    #[test]
+    fn it_should_accept_crockford_aliases_and_hyphens() {
+        let expected = 0x232221201f1e468544434241403f3e00;
+        let sut = decode_crockford(b"1348gj07ry8t2m8gt28503yfg0", false);
+        assert_eq!(sut.unwrap(), expected);
+        let sut = decode_crockford(b"I348GJ07RY8T2M8GT28503YFGO", false);
+        assert_eq!(sut.unwrap(), expected);
+        let sut = decode_crockford(b"l348-GJ07-RY8T-2M8G-T285-03YF-G0", false);
+        assert_eq!(sut.unwrap(), expected);
+        let sut = decode_crockford(b"1348-GJ07-RY8U-2M8G-T285-03YF-G0", false);
+        let kind = ParseErrorKind::InvalidCharacter('U');
+        let e = ParseError::new(Format::Crockford, 13, kind);
+        assert_eq!(sut.unwrap_err(), U64Error::InvalidEncodedString(e));
+    }
+    #[test]
+    fn it_should_catch_typos_in_checked_base64() {
+        let mode = ParseMode::Lenient;
+        let mut buf = [0u8; 23];
+        encode_base64_checked(0x232221201f1e468544434241403f3e00, &mut buf);
+        assert!(decode_base64_checked(&buf, mode).is_ok());
+        for i in 0..23 {
+            for char in BASE64_ALPHABET.iter().filter(|c| **c != buf[i]) {
+                let mut input = buf;
+                input[i] = *char;
+                assert!(decode_base64_checked(&input, mode).is_err());
+            }
+        }
+        for i in 0..22 {
+            let mut input = buf;
+            input.swap(i, i + 1);
+            if input != buf {
+                assert!(decode_base64_checked(&input, mode).is_err());
+            }
+        }
+    }
+}
+mod error {
+    use std::convert::TryFrom;
+
+    use crate::*;
+
+    #[test]
+    fn it_should_report_multi_byte_characters_whole() {
+        let input = "AAAAAAAAAAgABAAAAAAA\u{e9}";
+        let sut = Uuid4::try_from(input).unwrap_err();
+        let e = sut.parse_error().unwrap();
+        assert_eq!(e.offset(), 20);
+        assert_eq!(e.kind(), ParseErrorKind::InvalidCharacter('\u{e9}'));
+    }
+    #[test]
+    fn it_should_report_invalid_utf8_as_replacement_character() {
+        let mut input = *b"00000000000000800040000000000000";
+        input[4] = 0xff;
+        let sut = Uuid4::try_from(&input).unwrap_err();
+        let kind =
+            ParseErrorKind::InvalidCharacter(char::REPLACEMENT_CHARACTER);
+        let e = ParseError::new(Format::Hex, 4, kind);
+        assert_eq!(sut, U64Error::InvalidHexString(e));
+    }
+    #[test]
+    fn it_should_report_first_missing_hyphen() {
+        let input = "00000000-00000080-0040-0000000000000";
+        let sut = Uuid4::try_from(input).unwrap_err();
+        let kind = ParseErrorKind::ExpectedHyphen;
+        let e = ParseError::new(Format::Uuid, 13, kind);
+        assert_eq!(sut, U64Error::InvalidUuidString(e));
+    }
+    #[test]
+    fn it_should_report_a_misplaced_hyphen_where_one_was_expected() {
+        let input = "0000000-000000080-0040-0000000000000";
+        let sut = Uuid4::parse_strict(input).unwrap_err();
+        let kind = ParseErrorKind::ExpectedHyphen;
+        let e = ParseError::new(Format::Uuid, 8, kind);
+        assert_eq!(sut, U64Error::InvalidUuidString(e));
+        let input = "{00000000-0000-0080-004-0000000000000}";
+        let sut = Uuid4::parse_strict(input).unwrap_err();
+        let e = ParseError::new(Format::Braced, 24, kind);
+        assert_eq!(sut, U64Error::InvalidUuidString(e));
+    }
+    #[test]
+    fn it_should_describe_the_failure() {
+        let input = "0000000000000080004000000000000Z";
+        let sut = Uuid4::try_from(input).unwrap_err();
+        assert_eq!(
+            sut.to_string(),
+            concat!(
+                "The given hexadecimal string is invalid: invalid character ",
+                "'Z' at byte offset 31 in hexadecimal input ",
+                "(hexadecimal uses 0-9, a-f and A-F)"
+            )
+        );
+        assert_eq!(U64Error::InvalidStrLength(3).parse_error(), None);
+    }
+    #[test]
+    fn it_should_name_the_format_of_a_wrong_length() {
+        let sut = Uuid4::parse_as("00000000", Format::Uuid).unwrap_err();
+        assert_eq!(
+            sut.to_string(),
+            concat!(
+                "The given uuid string is invalid: wrong length of 8 bytes ",
+                "for uuid input (uuid must be exactly 36 characters long)"
+            )
+        );
+    }
+}
+mod format {
+    use crate::*;
+
+    #[test]
+    fn it_should_detect_each_format() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(
+            detect_format(sut.encode_base64(&mut [0u8; 22])),
+            Some(Format::Base64)
+        );
+        assert_eq!(
+            detect_format(sut.encode_base64_checked(&mut [0u8; 23])),
+            Some(Format::Base64Checked)
+        );
+        assert_eq!(
+            detect_format(sut.encode_crockford(&mut [0u8; 26])),
+            Some(Format::Crockford)
+        );
+        assert_eq!(
+            detect_format(sut.encode_hex_string(&mut [0u8; 32])),
+            Some(Format::Hex)
+        );
+        assert_eq!(
+            detect_format(sut.encode_uuid(&mut [0u8; 36])),
+            Some(Format::Uuid)
+        );
+        assert_eq!(
+            detect_format(sut.encode_braced(&mut [0u8; 38])),
+            Some(Format::Braced)
+        );
+        assert_eq!(
+            detect_format(sut.encode_urn(&mut [0u8; 45])),
+            Some(Format::Urn)
+        );
+        assert_eq!(detect_format(&sut.to_bytes_le()), Some(Format::Bytes));
+    }
+    #[test]
+    fn it_should_not_guess_when_ambiguous_or_unknown() {
+        assert_eq!(detect_format("AAAAAAAAAAAAAAAA"), None);
+        assert_eq!(detect_format(&[0x41u8; 16]), None);
+        assert_eq!(detect_format(&[0xffu8; 32]), None);
+        assert_eq!(detect_format("0000000000000080004000000000000Z"), None);
+        assert_eq!(detect_format("bad"), None);
+        assert_eq!(detect_format("0000000000000000000000000*"), None);
+        assert_eq!(detect_format("000000000000U00000000000000"), None);
+    }
+    #[test]
+    fn it_should_detect_crockford_check_symbol_at_the_end() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(
+            detect_format(sut.encode_crockford_checked(&mut [0u8; 27])),
+            Some(Format::Crockford)
+        );
+        assert_eq!(detect_format("0000000000000000000000000U*"), None);
+        assert_eq!(
+            detect_format("00000000000000000000000000~"),
+            Some(Format::Crockford)
+        );
+    }
+}
 mod uuid4 {
     use std::convert::TryFrom;
 
     use crate::*;
 
     /// Common input data for encoder tests.
+    #[cfg(feature = "alloc")]
     fn test_inputs_array_data() -> Vec<[u8; 16]> {
         vec![
             [0; 16],
@@ -77,6 +476,7 @@ mod uuid4 {
         ]
     }
 
+    #[cfg(all(feature = "std", feature = "rand"))]
     #[test]
     fn it_should_create_a_valid_uuid_in_new_with_none() {
         let sut = Uuid4::new(None);
@@ -86,6 +486,23 @@ mod uuid4 {
         let sut = Uuid4::try_from(&*uuid).unwrap();
         assert_eq!(sut.uuid0(), internal)
     }
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_should_create_reproducible_uuids_from_seeded_rng() {
+        use rand::rngs::mock::StepRng;
+        let mut rng = StepRng::new(42, 1);
+        let first = Uuid4::from_rng(&mut rng);
+        let mut rng = StepRng::new(42, 1);
+        assert_eq!(Uuid4::from_rng(&mut rng), first);
+        assert_ne!(Uuid4::from_rng(&mut rng), first);
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_create_a_valid_uuid_from_crypto_rng() {
+        let sut = Uuid4::from_crypto_rng(&mut rand::rngs::OsRng);
+        let result = Uuid4::try_from(&*sut.as_uuid()).unwrap();
+        assert_eq!(result, sut);
+    }
     #[test]
     fn it_should_correctly_decode_base64_from_str() {
         let inputs = vec![
@@ -146,6 +563,7 @@ mod uuid4 {
             assert_eq!(sut.uuid0(), expected);
         }
     }
+    #[cfg(feature = "alloc")]
     #[test]
     fn it_should_correctly_encode_based64() {
         let expects = vec![
@@ -168,6 +586,58 @@ mod uuid4 {
             assert_eq!(sut.as_base64(), expected)
         }
     }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_correctly_encode_sortable_base64() {
+        let expects = vec![
+            "----------V-0---------",
+            "2zzzzzzzzzjzxEzzzzzzzz",
+            "-E2kwE2kwEYkxE2kwE2kwE",
+            "2kwE2kwE2kgE0-wE2kwE2k",
+            "----------V-0--------0",
+            "-0-kJ83H40VN407G380FB0",
+            "-Y7X3V6lt5WJG2FZ4-Ens-",
+        ];
+        let inputs = test_inputs_array_data();
+        for (input, expected) in inputs.iter().zip(expects) {
+            let sut: Uuid4 = input.into();
+            assert_eq!(sut.as_base64_sortable(), expected);
+            assert_eq!(sut.encode_base64_sortable(&mut [0u8; 22]), expected);
+            let result = Uuid4::from_base64_sortable(expected).unwrap();
+            assert_eq!(result, sut);
+        }
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_sort_sortable_base64_in_numeric_order() {
+        let mut values: Vec<Uuid4> =
+            (0..200).map(|_| Uuid4::new(None)).collect();
+        let mut encoded: Vec<String> =
+            values.iter().map(|v| v.as_base64_sortable()).collect();
+        values.sort();
+        encoded.sort();
+        let decoded: Vec<Uuid4> = encoded
+            .iter()
+            .map(|e| Uuid4::from_base64_sortable(e).unwrap())
+            .collect();
+        assert_eq!(decoded, values);
+    }
+    #[test]
+    fn it_should_return_error_when_decoding_bad_sortable_base64_str() {
+        let input = "----------V-0---------+";
+        let kind = ParseErrorKind::InvalidLength;
+        let e = ParseError::new(Format::Base64Sortable, input.len(), kind);
+        let expected = U64Error::InvalidBase64String(e);
+        let sut = Uuid4::from_base64_sortable(input).unwrap_err();
+        assert_eq!(sut, expected);
+        let input = "----------V-0--------+";
+        let kind = ParseErrorKind::InvalidCharacter('+');
+        let e = ParseError::new(Format::Base64Sortable, 21, kind);
+        let expected = U64Error::InvalidBase64String(e);
+        let sut = Uuid4::from_base64_sortable(input).unwrap_err();
+        assert_eq!(sut, expected);
+    }
+    #[cfg(feature = "alloc")]
     #[test]
     fn it_should_correctly_encode_hex_string() {
         let expects = vec![
@@ -190,6 +660,7 @@ mod uuid4 {
             assert_eq!(sut.as_hex_string(), expected)
         }
     }
+    #[cfg(feature = "alloc")]
     #[test]
     fn it_should_correctly_encode_uuid() {
         let expects = vec![
@@ -212,6 +683,57 @@ mod uuid4 {
             assert_eq!(sut.as_uuid(), expected)
         }
     }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_encode_into_caller_buffers() {
+        let inputs = test_inputs_array_data();
+        for input in inputs.iter() {
+            let sut: Uuid4 = input.into();
+            assert_eq!(sut.encode_base64(&mut [0u8; 22]), sut.as_base64());
+            assert_eq!(
+                sut.encode_hex_string(&mut [0u8; 32]),
+                sut.as_hex_string()
+            );
+            assert_eq!(sut.encode_uuid(&mut [0u8; 36]), sut.as_uuid());
+        }
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_write_to_fmt_and_io_writers() {
+        let sut = Uuid4::new(None);
+        let mut text = String::new();
+        sut.write_base64(&mut text).unwrap();
+        sut.write_hex_string(&mut text).unwrap();
+        sut.write_uuid(&mut text).unwrap();
+        let mut bytes: Vec<u8> = Vec::new();
+        sut.write_base64_io(&mut bytes).unwrap();
+        sut.write_hex_string_io(&mut bytes).unwrap();
+        sut.write_uuid_io(&mut bytes).unwrap();
+        let expected = sut.as_base64() + &sut.as_hex_string() + &sut.as_uuid();
+        assert_eq!(text, expected);
+        assert_eq!(bytes, expected.into_bytes());
+    }
+    #[test]
+    fn it_should_display_base64_or_uuid_with_alternate_flag() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(sut.to_string(), "AjIiEgHx5GhURDQkFAPz4A");
+        assert_eq!(
+            format!("{:#}", sut),
+            "23222120-1f1e-4685-4443-4241403f3e00"
+        );
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_parse_all_formats_with_from_str() {
+        let sut = Uuid4::new(None);
+        for input in [sut.as_base64(), sut.as_hex_string(), sut.as_uuid()] {
+            let result: Uuid4 = input.parse().unwrap();
+            assert_eq!(result, sut);
+        }
+        let expected = U64Error::InvalidStrLength(3);
+        assert_eq!("bad".parse::<Uuid4>().unwrap_err(), expected);
+    }
+    #[cfg(feature = "alloc")]
     #[test]
     fn it_should_have_valid_default() {
         let expected = "00000000-0000-0080-0040-000000000000";
@@ -221,24 +743,284 @@ mod uuid4 {
     #[test]
     fn it_should_return_error_when_decoding_bad_base64_str() {
         let input = "AAAAAAAAAAgABAAAAAAAA+";
-        let expected = U64Error::InvalidBase64String;
+        let kind = ParseErrorKind::InvalidCharacter('+');
+        let e = ParseError::new(Format::Base64, 21, kind);
+        let expected = U64Error::InvalidBase64String(e);
         let sut = Uuid4::try_from(input).unwrap_err();
         assert_eq!(sut, expected);
     }
     #[test]
     fn it_should_return_error_when_decoding_bad_hex_str() {
         let input = "0000000000000080004000000000000Z";
-        let expected = U64Error::InvalidHexString;
+        let kind = ParseErrorKind::InvalidCharacter('Z');
+        let e = ParseError::new(Format::Hex, 31, kind);
+        let expected = U64Error::InvalidHexString(e);
         let sut = Uuid4::try_from(input).unwrap_err();
         assert_eq!(sut, expected);
     }
     #[test]
     fn it_should_return_error_when_decoding_bad_uuid_str() {
         let input = "00000000-0000-0080-0040-00000000000Z";
-        let expected = U64Error::InvalidUuidString;
+        let kind = ParseErrorKind::InvalidCharacter('Z');
+        let e = ParseError::new(Format::Uuid, 35, kind);
+        let expected = U64Error::InvalidUuidString(e);
         let sut = Uuid4::try_from(input).unwrap_err();
         assert_eq!(sut, expected);
     }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_encode_urn_braced_and_upper_case() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(
+            sut.as_hex_string_upper(),
+            "232221201F1E468544434241403F3E00"
+        );
+        assert_eq!(sut.as_uuid_upper(), "23222120-1F1E-4685-4443-4241403F3E00");
+        assert_eq!(
+            sut.as_urn(),
+            "urn:uuid:23222120-1f1e-4685-4443-4241403f3e00"
+        );
+        assert_eq!(sut.as_braced(), "{23222120-1f1e-4685-4443-4241403f3e00}");
+    }
+    #[test]
+    fn it_should_decode_urn_braced_and_upper_case() {
+        let expected = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let inputs = [
+            "232221201F1E468544434241403F3E00",
+            "23222120-1F1E-4685-4443-4241403F3E00",
+            "urn:uuid:23222120-1f1e-4685-4443-4241403f3e00",
+            "URN:UUID:23222120-1F1E-4685-4443-4241403F3E00",
+            "{23222120-1f1e-4685-4443-4241403f3e00}",
+            "{23222120-1F1E-4685-4443-4241403F3E00}",
+        ];
+        for input in inputs.iter() {
+            assert_eq!(Uuid4::try_from(*input).unwrap(), expected);
+            assert_eq!(input.parse::<Uuid4>().unwrap(), expected);
+            assert_eq!(Uuid4::parse_strict(*input).unwrap(), expected);
+        }
+    }
+    #[test]
+    fn it_should_return_error_when_decoding_bad_urn_or_braced_str() {
+        let input = "urn:uuid-23222120-1f1e-4685-4443-4241403f3e00";
+        let kind = ParseErrorKind::InvalidCharacter('-');
+        let e = ParseError::new(Format::Urn, 8, kind);
+        let expected = U64Error::InvalidUuidString(e);
+        assert_eq!(Uuid4::try_from(input).unwrap_err(), expected);
+        let input = "urn:uuid:23222120-1f1e-4685-4443-4241403f3e0Z";
+        let kind = ParseErrorKind::InvalidCharacter('Z');
+        let e = ParseError::new(Format::Urn, 44, kind);
+        let expected = U64Error::InvalidUuidString(e);
+        assert_eq!(Uuid4::try_from(input).unwrap_err(), expected);
+        let input = "{23222120-1f1e-4685-4443-4241403f3e00]";
+        let kind = ParseErrorKind::InvalidCharacter(']');
+        let e = ParseError::new(Format::Braced, 37, kind);
+        let expected = U64Error::InvalidUuidString(e);
+        assert_eq!(Uuid4::try_from(input).unwrap_err(), expected);
+        let input = "{23222120-1f1e-46854-443-4241403f3e00}";
+        assert!(Uuid4::try_from(input).is_ok());
+        let kind = ParseErrorKind::ExpectedHyphen;
+        let e = ParseError::new(Format::Braced, 19, kind);
+        let expected = U64Error::InvalidUuidString(e);
+        assert_eq!(Uuid4::parse_strict(input).unwrap_err(), expected);
+    }
+    #[test]
+    fn it_should_accept_canonical_input_when_strict() {
+        let expected = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let inputs = [
+            "AjIiEgHx5GhURDQkFAPz4A",
+            "232221201f1e468544434241403f3e00",
+            "23222120-1f1e-4685-4443-4241403f3e00",
+        ];
+        for input in inputs.iter() {
+            assert_eq!(Uuid4::parse_strict(*input).unwrap(), expected);
+            let sut = Uuid4::parse_with_mode(*input, ParseMode::Lenient);
+            assert_eq!(sut.unwrap(), expected);
+        }
+    }
+    #[test]
+    fn it_should_reject_what_lenient_mode_fixes_when_strict() {
+        let input = "2322212-01f1e-4685-4443-4241403f3e00";
+        assert!(Uuid4::try_from(input).is_ok());
+        let kind = ParseErrorKind::ExpectedHyphen;
+        let e = ParseError::new(Format::Uuid, 8, kind);
+        let expected = U64Error::InvalidUuidString(e);
+        assert_eq!(Uuid4::parse_strict(input).unwrap_err(), expected);
+        let input = "+32221201f1e468544434241403f3e00";
+        assert!(Uuid4::try_from(input).is_ok());
+        let kind = ParseErrorKind::InvalidCharacter('+');
+        let e = ParseError::new(Format::Hex, 0, kind);
+        let expected = U64Error::InvalidHexString(e);
+        assert_eq!(Uuid4::parse_strict(input).unwrap_err(), expected);
+        let input = "EjIiEgHx5GhURDQkFAPz4A";
+        assert!(Uuid4::try_from(input).is_ok());
+        let kind = ParseErrorKind::InvalidFillBits;
+        let e = ParseError::new(Format::Base64, 0, kind);
+        let expected = U64Error::InvalidBase64String(e);
+        assert_eq!(Uuid4::parse_strict(input).unwrap_err(), expected);
+        let input = "23222120-1f1e-4685-4403-4241403f3e00";
+        assert!(Uuid4::try_from(input).is_ok());
+        let expected = U64Error::InvalidVersionOrVariant;
+        assert_eq!(Uuid4::parse_strict(input).unwrap_err(), expected);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_round_trip_crockford_with_check_symbol() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(sut.as_crockford(), "1348GJ07RY8T2M8GT28503YFG0");
+        let result = sut.as_crockford_checked();
+        assert_eq!(result, "1348GJ07RY8T2M8GT28503YFG0J");
+        assert_eq!(Uuid4::from_crockford_checked(&result).unwrap(), sut);
+        let result = Uuid4::from_crockford(&sut.as_crockford()).unwrap();
+        assert_eq!(result, sut);
+    }
+    #[test]
+    fn it_should_return_error_when_crockford_is_invalid() {
+        // Swapped symbols.
+        let input = "3148GJ07RY8T2M8GT28503YFG0J";
+        let kind = ParseErrorKind::ChecksumMismatch;
+        let e = ParseError::new(Format::Crockford, 26, kind);
+        let expected = U64Error::ChecksumMismatch(e);
+        let sut = Uuid4::from_crockford_checked(input).unwrap_err();
+        assert_eq!(sut, expected);
+        // Mistyped symbol.
+        let input = "1348GJ07RY8T2M8GT28503YFG1J";
+        let sut = Uuid4::from_crockford_checked(input).unwrap_err();
+        assert_eq!(sut, expected);
+        let input = "1348GJ07RY8T2M8GT28503YFGU";
+        let kind = ParseErrorKind::InvalidCharacter('U');
+        let e = ParseError::new(Format::Crockford, 25, kind);
+        let sut = Uuid4::from_crockford(input).unwrap_err();
+        assert_eq!(sut, U64Error::InvalidEncodedString(e));
+        let input = "80000000000000000000000000";
+        let kind = ParseErrorKind::Overflow;
+        let e = ParseError::new(Format::Crockford, 0, kind);
+        let sut = Uuid4::from_crockford(input).unwrap_err();
+        assert_eq!(sut, U64Error::InvalidEncodedString(e));
+        let input = "1348GJ07RY8T2M8GT28503YFG";
+        let sut = Uuid4::from_crockford(input).unwrap_err();
+        let kind = ParseErrorKind::InvalidLength;
+        let e = ParseError::new(Format::Crockford, 25, kind);
+        assert_eq!(sut, U64Error::InvalidEncodedString(e));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_round_trip_base64_with_check_character() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let result = sut.as_base64_checked();
+        assert_eq!(result, "AjIiEgHx5GhURDQkFAPz4AM");
+        assert_eq!(Uuid4::try_from(&*result).unwrap(), sut);
+        assert_eq!(Uuid4::parse_strict(&result).unwrap(), sut);
+    }
+    #[test]
+    fn it_should_return_error_when_check_character_does_not_match() {
+        let kind = ParseErrorKind::ChecksumMismatch;
+        let e = ParseError::new(Format::Base64Checked, 22, kind);
+        let expected = U64Error::ChecksumMismatch(e);
+        let sut = Uuid4::try_from("AjIiEgHx5GhURDQkFAPz4AN").unwrap_err();
+        assert_eq!(sut, expected);
+        let sut = Uuid4::try_from("AjIiEgHx5GhURQDkFAPz4AM").unwrap_err();
+        assert_eq!(sut, expected);
+        let sut = Uuid4::try_from(b"AjIiEgHx5GhURDQkFAPz4A=").unwrap_err();
+        let kind = ParseErrorKind::InvalidCharacter('=');
+        let e = ParseError::new(Format::Base64Checked, 22, kind);
+        assert_eq!(sut, U64Error::InvalidBase64String(e));
+    }
+    #[test]
+    fn it_should_build_and_inspect_values_in_const_context() {
+        const SUT: Uuid4 =
+            Uuid4::from_u128_unchecked(0x232221201f1e468544434241403f3e00);
+        const VALUE: u128 = SUT.as_u128();
+        const VERSION: u8 = SUT.version();
+        let copy = SUT;
+        assert_eq!(copy.uuid0(), VALUE);
+        assert_eq!(VERSION, 4);
+        assert_eq!(SUT.variant(), Variant::Rfc);
+        assert_eq!(Uuid4::NIL.as_u128(), 0);
+        assert_eq!(Uuid4::NIL.version(), 0);
+        assert_eq!(Uuid4::NIL.variant(), Variant::Ncs);
+        assert_eq!(Uuid4::MAX.version(), 15);
+        assert_eq!(Uuid4::MAX.variant(), Variant::Future);
+        assert_eq!(Uuid4::from_u128_unchecked(0).version(), 0);
+        assert_eq!(Uuid4::from(0).version(), 4);
+        assert_ne!(Uuid4::default(), Uuid4::NIL);
+    }
+    #[test]
+    fn it_should_report_fields_from_legacy_layout() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(Uuid::version(&sut), 4);
+        assert_eq!(Uuid::variant(&sut), Variant::Rfc);
+        assert_eq!(sut.time_low(), 0x003e3f40);
+        assert_eq!(sut.time_mid(), 0x4142);
+        assert_eq!(sut.time_hi_and_version(), 0x4344);
+        assert_eq!(sut.clock_seq(), 0x8546);
+        assert_eq!(sut.node(), [0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23]);
+        // The text uses the big-endian value instead.
+        let mut buf = [0u8; 36];
+        let text = sut.encode_uuid(&mut buf);
+        assert_eq!(text, "23222120-1f1e-4685-4443-4241403f3e00");
+        assert!(!sut.is_nil());
+        assert!(Uuid4::NIL.is_nil());
+        assert!(Uuid4::MAX.is_max());
+        assert!(!Uuid4::default().is_max());
+    }
+    #[test]
+    fn it_should_parse_the_same_in_const_context() {
+        const SUT: Uuid4 = uuid64!("23222120-1f1e-4685-4443-4241403f3e00");
+        assert_eq!(SUT, Uuid4::from(0x232221201f1e468544434241403f3e00));
+        let inputs = [
+            "AjIiEgHx5GhURDQkFAPz4A",
+            "232221201f1e468544434241403f3e00",
+            "+32221201f1e468544434241403f3e00",
+            "23222120-1f1e-4685-4443-4241403f3e00",
+            "AjIiEgHx5GhURDQkFAPz\u{e9}",
+            "0000000000000080004000000000000Z",
+            "00000000-00000080-0040-0000000000000",
+            "bad",
+        ];
+        for input in inputs.iter() {
+            assert_eq!(Uuid4::parse_const(input), Uuid4::try_from(*input));
+        }
+    }
+    #[test]
+    fn it_should_only_use_the_given_format_in_parse_as() {
+        let expected = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let mut buf = [0u8; 22];
+        let input = expected.encode_base64(&mut buf);
+        let sut = Uuid4::parse_as(input, Format::Base64).unwrap();
+        assert_eq!(sut, expected);
+        let sut = Uuid4::parse_as(input, Format::Base64Sortable).unwrap();
+        assert_ne!(sut, expected);
+        let input = "1348GJ07RY8T2M8GT28503YFG0J";
+        let sut = Uuid4::parse_as(input, Format::Crockford).unwrap();
+        assert_eq!(sut, expected);
+        let input = expected.to_bytes_le();
+        let sut = Uuid4::parse_as(&input, Format::Bytes).unwrap();
+        assert_eq!(sut, expected);
+        let input = "23222120-1f1e-4685-4443-4241403f3e00";
+        let sut = Uuid4::parse_as(input, Format::Hex).unwrap_err();
+        let e = ParseError::new(Format::Hex, 36, ParseErrorKind::InvalidLength);
+        assert_eq!(sut, U64Error::InvalidHexString(e));
+        let e = sut.parse_error().unwrap();
+        assert_eq!(e.hint(), "hexadecimal must be exactly 32 characters long");
+        let sut = Uuid4::parse_as(&[0u8; 15][..], Format::Bytes).unwrap_err();
+        let e =
+            ParseError::new(Format::Bytes, 15, ParseErrorKind::InvalidLength);
+        assert_eq!(sut, U64Error::InvalidEncodedString(e));
+        let sut = Uuid4::parse_as(input, Format::Custom).unwrap_err();
+        assert_eq!(sut, U64Error::UnsupportedFormat(Format::Custom));
+    }
+    #[test]
+    fn it_should_not_take_printable_text_as_raw_bytes() {
+        let input = "AAAAAAAAAAgABAAA";
+        let sut = Uuid4::try_from(input.as_bytes()).unwrap_err();
+        assert_eq!(sut, U64Error::AmbiguousFormat);
+        let sut = Uuid4::try_from(input).unwrap_err();
+        assert_eq!(sut, U64Error::InvalidStrLength(16));
+        let sut = Uuid4::parse_as(input, Format::Bytes).unwrap();
+        assert_eq!(sut, Uuid4::from(b"AAAAAAAAAAgABAAA"));
+        let input: &[u8] = &[0u8; 16];
+        assert_eq!(Uuid4::try_from(input).unwrap(), Uuid4::default());
+    }
     #[test]
     fn it_should_return_error_when_string_is_invalid_length() {
         // Length = 21
@@ -246,23 +1028,608 @@ mod uuid4 {
         let expected = U64Error::InvalidStrLength(input.len());
         let sut = Uuid4::try_from(input).unwrap_err();
         assert_eq!(sut, expected);
-        // Length = 23
-        let input = "AAAAAAAAAAgABAAAAAAAABB";
+        // Length = 24
+        let input = "AAAAAAAAAAgABAAAAAAAABBB";
         let expected = U64Error::InvalidStrLength(input.len());
         let sut = Uuid4::try_from(input).unwrap_err();
         assert_eq!(sut, expected);
     }
+}
+mod uuid4rfc {
+    use std::convert::TryFrom;
+
+    use crate::*;
+
     #[test]
-    #[ignore]
-    fn it_should_junk() {
-        let sut = Uuid4::default();
-        let ser = serde_json::to_string(&sut).unwrap();
-        let deser: Uuid4 = serde_json::from_str(&ser).unwrap();
-        eprintln!("serialized = {}", ser);
-        eprintln!("deserialized = {:?}", deser);
+    fn it_should_report_fields_from_rfc_layout() {
+        /// Only relies on the trait like code handling any of the types.
+        fn is_v4<U: Uuid>(uuid: &U) -> bool {
+            uuid.version() == 4 && uuid.variant() == Variant::Rfc
+        }
+        let sut = Uuid4Rfc::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(sut.time_low(), 0x23222120);
+        assert_eq!(sut.time_mid(), 0x1f1e);
+        assert_eq!(sut.time_hi_and_version(), 0x4685);
+        assert_eq!(sut.clock_seq(), 0x8443);
+        assert_eq!(sut.node(), [0x42, 0x41, 0x40, 0x3f, 0x3e, 0x00]);
+        assert!(is_v4(&sut));
+        assert!(is_v4(&Uuid4::default()));
+        assert!(!is_v4(&Uuid7::default()));
+        assert!(!is_v4(&Namespace::DNS));
+        assert_eq!(Namespace::DNS.version(), 1);
+    }
+    #[test]
+    fn it_should_read_version_and_variant_from_rfc_layout() {
+        let sut = Uuid4Rfc::from(0x232221201f1e468544434241403f3e00);
+        assert_eq!(sut.version(), 4);
+        assert_eq!(sut.variant(), Variant::Rfc);
+        let sut = Uuid4Rfc::from_u128_unchecked(Uuid4::default().uuid0());
+        assert_eq!(sut.version(), 0);
+        assert_eq!(Uuid7::default().version(), 7);
+        #[cfg(feature = "v5")]
+        assert_eq!(Uuid5::default().version(), 5);
+        #[cfg(feature = "v3")]
+        assert_eq!(Uuid3::default().version(), 3);
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_create_a_valid_uuid_in_new_with_none() {
+        let sut = Uuid4Rfc::new(None);
+        let uuid = sut.as_uuid();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+        assert!("89ab".contains(&uuid[19..20]));
+        let result = Uuid4Rfc::try_from(&*uuid).unwrap();
+        assert_eq!(result, sut)
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_have_valid_default() {
+        let expected = "00000000-0000-4000-8000-000000000000";
+        let sut = Uuid4Rfc::default();
+        assert_eq!(sut.as_uuid(), expected)
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_treat_bytes_as_big_endian() {
+        let input = [1, 3, 5, 9, 17, 33, 65, 129, 129, 65, 33, 17, 9, 5, 3, 1];
+        let sut: Uuid4Rfc = (&input).into();
+        assert_eq!(sut.as_uuid(), "01030509-1121-4181-8141-211109050301")
+    }
+    #[test]
+    fn it_should_convert_bytes_in_each_byte_order() {
+        let be = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x46, 0x77, 0x88, 0x99, 0xaa,
+            0xbb, 0xcc, 0xdd, 0xee, 0xff,
+        ];
+        let guid = [
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x46, 0x88, 0x99, 0xaa,
+            0xbb, 0xcc, 0xdd, 0xee, 0xff,
+        ];
+        let mut le = be;
+        le.reverse();
+        let expected =
+            Uuid4Rfc::try_from("00112233-4455-4677-8899-aabbccddeeff");
+        let expected = expected.unwrap();
+        assert_eq!(Uuid4Rfc::from_bytes_be(be), expected);
+        assert_eq!(Uuid4Rfc::from_bytes_le(le), expected);
+        assert_eq!(Uuid4Rfc::from_guid_bytes(guid), expected);
+        assert_eq!(expected.to_bytes_be(), be);
+        assert_eq!(expected.to_bytes_le(), le);
+        assert_eq!(expected.to_guid_bytes(), guid);
+    }
+    #[test]
+    fn it_should_correctly_decode_uuid_from_str() {
+        let input = "003e3f40-4142-4344-8546-1e1f20212223";
+        let sut = Uuid4Rfc::try_from(input).unwrap();
+        assert_eq!(sut.uuid0(), 0x003e3f404142434485461e1f20212223);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn it_should_migrate_from_legacy_layout() {
+        let legacy = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let sut = Uuid4Rfc::from(legacy);
+        assert_eq!(sut.as_uuid(), "003e3f40-4142-4344-8546-1e1f20212223");
+        assert_eq!(Uuid4::from(sut), legacy);
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_round_trip_random_values_through_legacy_layout() {
+        for _ in 0..100 {
+            let legacy = Uuid4::new(None);
+            let sut = Uuid4Rfc::from(legacy);
+            assert_eq!(&sut.as_uuid()[14..15], "4");
+            assert_eq!(Uuid4::from(sut), legacy);
+        }
+    }
+}
+mod uuid7 {
+    #[cfg(all(feature = "std", feature = "rand"))]
+    use std::convert::TryFrom;
+
+    use crate::*;
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_create_a_valid_uuid_in_new_with_none() {
+        let sut = Uuid7::new(None);
+        let uuid = sut.as_uuid();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "7");
+        assert!("89ab".contains(&uuid[19..20]));
+        let result = Uuid7::try_from(&*uuid).unwrap();
+        assert_eq!(result, sut)
+    }
+    #[test]
+    fn it_should_have_valid_default() {
+        let expected = "00000000-0000-7000-8000-000000000000";
+        let sut = Uuid7::default();
+        assert_eq!(sut.encode_uuid(&mut [0u8; 36]), expected)
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_put_timestamp_in_first_48_bits() {
+        let sut = Uuid7::from_timestamp_millis(0x017f22e279b0, None);
+        assert_eq!(&sut.as_uuid()[..13], "017f22e2-79b0");
+        assert_eq!(sut.timestamp_millis(), 0x017f22e279b0);
+    }
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_should_create_reproducible_uuids_from_seeded_rng() {
+        use rand::rngs::mock::StepRng;
+        let mut rng = StepRng::new(7, 1);
+        let sut = Uuid7::from_timestamp_millis_and_rng(1_000, &mut rng);
+        let mut rng = StepRng::new(7, 1);
+        let result = Uuid7::from_timestamp_millis_and_rng(1_000, &mut rng);
+        assert_eq!(result, sut);
+        assert_eq!(sut.timestamp_millis(), 1_000);
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_order_by_timestamp() {
+        let mut rng = rand::thread_rng();
+        let older = Uuid7::from_timestamp_millis(1_000, &mut rng);
+        let newer = Uuid7::from_timestamp_millis(1_001, &mut rng);
+        assert!(older < newer);
+        assert!(older.as_hex_string() < newer.as_hex_string());
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_correctly_decode_all_formats() {
+        let sut = Uuid7::from_timestamp_millis(0x017f22e279b0, None);
+        for input in [sut.as_base64(), sut.as_hex_string(), sut.as_uuid()] {
+            assert_eq!(Uuid7::try_from(&*input).unwrap(), sut);
+            assert_eq!(Uuid7::parse_strict(&*input).unwrap(), sut);
+        }
+        let input = "23222120-1f1e-4685-4443-4241403f3e00";
+        let expected = U64Error::InvalidVersionOrVariant;
+        assert_eq!(Uuid7::parse_strict(input).unwrap_err(), expected);
+    }
+}
+#[cfg(any(feature = "uuid", feature = "ulid"))]
+mod interop {
+    use std::convert::TryFrom;
+
+    use crate::*;
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn it_should_keep_rfc_bytes_when_converting_uuid() {
+        let input = "00112233-4455-4677-8899-aabbccddeeff";
+        let other = uuid::Uuid::parse_str(input).unwrap();
+        let sut = Uuid4Rfc::try_from(other).unwrap();
+        assert_eq!(sut, Uuid4Rfc::try_from(input).unwrap());
+        assert_eq!(uuid::Uuid::from(sut), other);
+        let legacy = Uuid4::try_from(other).unwrap();
+        assert_eq!(legacy.to_bytes_le(), *other.as_bytes());
+        assert_eq!(Uuid4Rfc::from(legacy), sut);
+        assert_eq!(uuid::Uuid::from(legacy), other);
+    }
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn it_should_use_legacy_bytes_for_uuid4_text() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let other = uuid::Uuid::from(sut).to_string();
+        assert_eq!(other, "003e3f40-4142-4344-8546-1e1f20212223");
+        assert_eq!(sut.as_uuid(), "23222120-1f1e-4685-4443-4241403f3e00");
+        let other = uuid::Uuid::from_u128(sut.as_u128()).to_string();
+        assert_eq!(other, sut.as_uuid());
+    }
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn it_should_reject_other_versions_when_converting_uuid() {
+        let expected = U64Error::InvalidVersionOrVariant;
+        let sut = Uuid4::try_from(uuid::Uuid::nil()).unwrap_err();
+        assert_eq!(sut, expected);
+        let other = uuid::Uuid::from_u128(0x017f22e279b07cc398c4dc0c0c07398f);
+        assert_eq!(Uuid4Rfc::try_from(other).unwrap_err(), expected);
+        let sut = Uuid7::try_from(other).unwrap();
+        assert_eq!(sut.timestamp_millis(), 0x017f22e279b0);
+    }
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn it_should_keep_timestamp_when_converting_ulid() {
+        let sut = Uuid7::from(0x017f22e279b07cc398c4dc0c0c07398f);
+        let other = ulid::Ulid::from(sut);
+        assert_eq!(other.timestamp_ms(), sut.timestamp_millis());
+        assert_eq!(Uuid7::try_from(other).unwrap(), sut);
+        let other = ulid::Ulid::from_parts(0x017f22e279b0, 0);
+        let expected = U64Error::InvalidVersionOrVariant;
+        assert_eq!(Uuid7::try_from(other).unwrap_err(), expected);
+        let sut = Uuid7::from(&other.to_bytes());
+        assert_eq!(sut.timestamp_millis(), other.timestamp_ms());
+    }
+}
+mod namespace {
+    use crate::*;
+
+    #[test]
+    fn it_should_have_correct_predefined_namespaces() {
+        let expects = vec![
+            (Namespace::DNS, "6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            (Namespace::URL, "6ba7b811-9dad-11d1-80b4-00c04fd430c8"),
+            (Namespace::OID, "6ba7b812-9dad-11d1-80b4-00c04fd430c8"),
+            (Namespace::X500, "6ba7b814-9dad-11d1-80b4-00c04fd430c8"),
+        ];
+        for (sut, expected) in expects {
+            assert_eq!(sut.encode_uuid(&mut [0u8; 36]), expected);
+        }
+    }
+    #[test]
+    fn it_should_look_up_predefined_namespaces_by_name() {
+        assert_eq!(Namespace::by_name("DNS"), Some(Namespace::DNS));
+        assert_eq!(Namespace::by_name("x500"), Some(Namespace::X500));
+        assert_eq!(Namespace::by_name("unknown"), None);
+    }
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn it_should_use_uuid4_as_custom_namespace() {
+        let uuid = Uuid4::new(None);
+        let sut = Namespace::from(&uuid);
+        assert_eq!(sut.as_uuid(), uuid.as_uuid());
+    }
+}
+#[cfg(feature = "serde")]
+mod serde {
+    use crate::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Record {
+        #[serde(with = "crate::serde::base64")]
+        base64: Uuid4,
+        #[serde(with = "crate::serde::simple")]
+        simple: Uuid4,
+        #[serde(with = "crate::serde::hyphenated")]
+        hyphenated: Uuid7,
+    }
+
+    #[test]
+    fn it_should_serialize_as_base64_string_when_human_readable() {
+        let sut = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let result = serde_json::to_string(&sut).unwrap();
+        assert_eq!(result, r#""AjIiEgHx5GhURDQkFAPz4A""#);
+    }
+    #[test]
+    fn it_should_deserialize_any_format_when_human_readable() {
+        let expected = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let inputs = vec![
+            r#""AjIiEgHx5GhURDQkFAPz4A""#,
+            r#""232221201f1e468544434241403f3e00""#,
+            r#""23222120-1f1e-4685-4443-4241403f3e00""#,
+        ];
+        for input in inputs {
+            let sut: Uuid4 = serde_json::from_str(input).unwrap();
+            assert_eq!(sut, expected);
+        }
+        let sut: Uuid4 = serde_json::from_str("0").unwrap();
+        assert_eq!(sut, Uuid4::default());
+    }
+    #[test]
+    fn it_should_reject_bad_strings_when_human_readable() {
+        assert!(serde_json::from_str::<Uuid4>(r#""AAAAAAAAAAAAAAAA""#).is_err());
+        assert!(serde_json::from_str::<Uuid4>(r#""bad""#).is_err());
+        assert!(serde_json::from_str::<Uuid4>("-1").is_err());
+    }
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Fixed {
+        #[serde(with = "crate::serde::fixed")]
+        id: Uuid4Rfc,
+    }
+
+    #[test]
+    fn it_should_use_a_byte_string_when_binary() {
+        let sut = Uuid4Rfc::from(0x232221201f1e468544434241403f3e00);
+        let mut result = Vec::new();
+        ciborium::into_writer(&sut, &mut result).unwrap();
+        // CBOR byte string header for 16 bytes.
+        assert_eq!(result[0], 0x50);
+        assert_eq!(result[1..], sut.uuid0().to_be_bytes());
+        let result: Uuid4Rfc = ciborium::from_reader(&result[..]).unwrap();
+        assert_eq!(result, sut);
+        let result = bincode::serialize(&sut).unwrap();
+        assert_eq!(result.len(), 8 + 16);
+        let result: Uuid4Rfc = bincode::deserialize(&result).unwrap();
+        assert_eq!(result, sut);
+    }
+    #[test]
+    fn it_should_use_16_bytes_with_fixed_submodule() {
+        let sut =
+            Fixed { id: Uuid4Rfc::from(0x232221201f1e468544434241403f3e00) };
+        let result = bincode::serialize(&sut).unwrap();
+        assert_eq!(result, sut.id.uuid0().to_be_bytes());
+        let result: Fixed = bincode::deserialize(&result).unwrap();
+        assert_eq!(result, sut);
+    }
+    #[test]
+    fn it_should_read_legacy_u128_payloads() {
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Legacy {
+            #[serde(with = "crate::serde::integer")]
+            id: Uuid4,
+        }
+        #[derive(Deserialize)]
+        struct LegacyBinary {
+            #[serde(with = "crate::serde::fixed")]
+            id: Uuid4,
+        }
+        let input = r#"{"id":2361201255833332088832}"#;
+        let result: Legacy = serde_json::from_str(input).unwrap();
+        assert_eq!(result.id, Uuid4::default());
+        assert_eq!(serde_json::to_string(&result).unwrap(), input);
+        // The old derive wrote the little-endian bytes of the value.
+        let input = bincode::serialize(&Uuid4::default().uuid0()).unwrap();
+        let result: LegacyBinary = bincode::deserialize(&input).unwrap();
+        assert_eq!(result.id, Uuid4::default());
+    }
+    #[test]
+    fn it_should_force_format_with_submodules() {
+        let sut = Record {
+            base64: Uuid4::default(),
+            simple: Uuid4::default(),
+            hyphenated: Uuid7::default(),
+        };
+        let result = serde_json::to_string(&sut).unwrap();
+        let expected = concat!(
+            r#"{"base64":"AAAAAAAAAAgABAAAAAAAAA","#,
+            r#""simple":"00000000000000800040000000000000","#,
+            r#""hyphenated":"00000000-0000-7000-8000-000000000000"}"#
+        );
+        assert_eq!(result, expected);
+        let result: Record = serde_json::from_str(&result).unwrap();
+        assert_eq!(result, sut);
+        let input = concat!(
+            r#"{"base64":"00000000000000800040000000000000","#,
+            r#""simple":"00000000000000800040000000000000","#,
+            r#""hyphenated":"00000000-0000-7000-8000-000000000000"}"#
+        );
+        assert!(serde_json::from_str::<Record>(input).is_err());
+    }
+}
+#[cfg(feature = "sqlite")]
+mod sql_types {
+    use crate::*;
+    use diesel::{
+        dsl::sql, prelude::*, sql_types::Text, sqlite::SqliteConnection,
+    };
+
+    diesel::table! {
+        use crate::{Uuid4Proxy, Uuid4RfcProxy, Uuid7Proxy, UuidBinaryProxy};
+        use diesel::sql_types::Integer;
+
+        records (id) {
+            id -> Integer,
+            text_id -> Uuid4Proxy,
+            blob_id -> UuidBinaryProxy,
+            v7_id -> Uuid7Proxy,
+            rfc_id -> Uuid4RfcProxy,
+        }
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query(
+            "CREATE TABLE records (id INTEGER PRIMARY KEY, text_id TEXT, \
+             blob_id BLOB, v7_id TEXT, rfc_id TEXT)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn it_should_round_trip_through_sqlite() {
+        let mut conn = connection();
+        let uuid4 = Uuid4::from(0x232221201f1e468544434241403f3e00);
+        let uuid7 = Uuid7::from(0x017f22e279b071238456789abcdef012);
+        diesel::insert_into(records::table)
+            .values((
+                records::id.eq(1),
+                records::text_id.eq(&uuid4),
+                records::blob_id.eq(&uuid4),
+                records::v7_id.eq(&uuid7),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+        let result = records::table
+            .select((records::text_id, records::blob_id, records::v7_id))
+            .first::<(Uuid4, Uuid4, Uuid7)>(&mut conn)
+            .unwrap();
+        assert_eq!(result, (uuid4, uuid4, uuid7));
+        let found = records::table
+            .filter(records::text_id.eq(&uuid4))
+            .select(records::id)
+            .first::<i32>(&mut conn)
+            .unwrap();
+        assert_eq!(found, 1);
+    }
+    #[test]
+    fn it_should_store_text_and_blob_representations() {
+        let mut conn = connection();
+        let uuid = Uuid4Rfc::from(0x232221201f1e468544434241403f3e00);
+        diesel::insert_into(records::table)
+            .values((
+                records::id.eq(1),
+                records::rfc_id.eq(&uuid),
+                records::blob_id.eq(&uuid),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+        let (text, hex) = records::table
+            .select((
+                sql::<Text>("rfc_id || ''"),
+                sql::<Text>("lower(hex(blob_id))"),
+            ))
+            .first::<(String, String)>(&mut conn)
+            .unwrap();
+        assert_eq!(text, uuid.as_base64());
+        assert_eq!(hex, uuid.as_hex_string());
+    }
+    #[test]
+    fn it_should_store_legacy_blob_in_rfc_order() {
+        let mut conn = connection();
+        let uuid = Uuid4::default();
+        diesel::insert_into(records::table)
+            .values((records::id.eq(1), records::blob_id.eq(&uuid)))
+            .execute(&mut conn)
+            .unwrap();
+        let hex = records::table
+            .select(sql::<Text>("lower(hex(blob_id))"))
+            .first::<String>(&mut conn)
+            .unwrap();
+        assert_eq!(hex, Uuid4Rfc::from(uuid).as_hex_string());
+        let result = records::table
+            .select(records::blob_id)
+            .first::<Uuid4>(&mut conn)
+            .unwrap();
+        assert_eq!(result, uuid);
     }
 }
-#[cfg(feature = "wasm-bindgen")]
+#[cfg(feature = "postgres")]
+mod pg_types {
+    use crate::*;
+    use diesel::{
+        deserialize::FromSql,
+        pg::{Pg, PgMetadataLookup, PgTypeMetadata, PgValue},
+        query_builder::{bind_collector::RawBytesBindCollector, BindCollector},
+    };
+    use std::num::NonZeroU32;
+
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+            unreachable!("only built-in types are used")
+        }
+    }
+
+    fn bind<U>(value: &U) -> Vec<u8>
+    where
+        U: diesel::serialize::ToSql<PgUuidProxy, Pg>,
+    {
+        let mut collector = RawBytesBindCollector::<Pg>::new();
+        collector
+            .push_bound_value::<PgUuidProxy, U>(value, &mut NoLookup)
+            .unwrap();
+        collector.binds.remove(0).unwrap()
+    }
+
+    #[test]
+    fn it_should_write_rfc_bytes_for_native_uuid() {
+        let uuid4 = Uuid4::default();
+        let uuid4rfc = Uuid4Rfc::from(uuid4);
+        let uuid7 = Uuid7::from(0x017f22e279b071238456789abcdef012);
+        assert_eq!(bind(&uuid4), uuid4rfc.uuid0().to_be_bytes());
+        assert_eq!(bind(&uuid4rfc), uuid4rfc.uuid0().to_be_bytes());
+        assert_eq!(bind(&uuid7), uuid7.uuid0().to_be_bytes());
+    }
+    #[test]
+    fn it_should_read_rfc_bytes_for_native_uuid() {
+        let uuid = Uuid4::default();
+        let bytes = bind(&uuid);
+        let oid = NonZeroU32::new(2950).unwrap();
+        let value = PgValue::new(&bytes, &oid);
+        let result: Uuid4 =
+            FromSql::<PgUuidProxy, Pg>::from_sql(value).unwrap();
+        assert_eq!(result, uuid);
+    }
+}
+#[cfg(any(feature = "v3", feature = "v5"))]
+mod name_based {
+    use crate::*;
+
+    /// Hashes the namespace and name with one of the name-based types.
+    type Hash = fn(&Namespace, &str) -> AnyUuid;
+
+    #[cfg(feature = "v3")]
+    fn v3(namespace: &Namespace, name: &str) -> AnyUuid {
+        Uuid3::new(namespace, name).into()
+    }
+    #[cfg(feature = "v5")]
+    fn v5(namespace: &Namespace, name: &str) -> AnyUuid {
+        Uuid5::new(namespace, name).into()
+    }
+    /// Known values from the `uuid` module of Python.
+    fn cases() -> Vec<(Hash, Namespace, &'static str, &'static str)> {
+        let mut cases: Vec<(Hash, Namespace, &str, &str)> = Vec::new();
+        #[cfg(feature = "v3")]
+        cases.extend_from_slice(&[
+            (
+                v3,
+                Namespace::DNS,
+                "python.org",
+                "6fa459ea-ee8a-3ca4-894e-db77e160355e",
+            ),
+            (
+                v3,
+                Namespace::URL,
+                "https://www.python.org/",
+                "c49f2dd9-81e2-3156-924e-c704c7ea95de",
+            ),
+            (
+                v3,
+                Namespace::OID,
+                "1.3.6.1",
+                "dd1a1cef-13d5-368a-ad82-eca71acd4cd1",
+            ),
+        ]);
+        #[cfg(feature = "v5")]
+        cases.extend_from_slice(&[
+            (
+                v5,
+                Namespace::DNS,
+                "python.org",
+                "886313e1-3b8a-5372-9b90-0c9aee199e5d",
+            ),
+            (
+                v5,
+                Namespace::URL,
+                "https://www.python.org/",
+                "5406f80d-92e9-51cd-a176-77445955e733",
+            ),
+            (
+                v5,
+                Namespace::X500,
+                "cn=John Doe",
+                "6b28d549-d26e-5bfc-ae5e-9a39af63dc3f",
+            ),
+        ]);
+        cases
+    }
+
+    #[test]
+    fn it_should_match_known_values() {
+        for (hash, namespace, name, expected) in cases() {
+            let sut = hash(&namespace, name);
+            assert_eq!(sut.encode_uuid(&mut [0u8; 36]), expected);
+            assert_eq!(hash(&namespace, name), sut);
+            let other = Namespace::from(Uuid4Rfc::default());
+            assert_ne!(hash(&other, name), sut);
+        }
+    }
+}
+#[cfg(feature = "wasm")]
 mod uuid4gen {
     use crate::*;
 
@@ -273,4 +1640,57 @@ mod uuid4gen {
         assert_eq!(sut.gen_hex_string().len(), 32);
         assert_eq!(sut.gen_uuid().len(), 36);
     }
+    #[test]
+    fn it_should_use_the_given_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut sut = Uuid4Gen::with_rng(StdRng::seed_from_u64(1));
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(sut.gen_uuid(), Uuid4::from_rng(&mut rng).as_uuid());
+        let mut sut = Uuid4Gen::with_crypto_rng(rand::rngs::OsRng);
+        assert_eq!(sut.gen_base64().len(), 22);
+    }
+}
+#[cfg(all(feature = "std", feature = "rand"))]
+mod uuid7gen {
+    use rand::rngs::mock::StepRng;
+
+    use crate::*;
+
+    #[test]
+    fn it_should_generate_strictly_increasing_values() {
+        let mut sut = Uuid7Gen::new();
+        let mut last = sut.generate().unwrap();
+        for _ in 0..1000 {
+            let next = sut.generate().unwrap();
+            assert!(next > last);
+            assert_eq!(Uuid7::parse_strict(&next.as_uuid()).unwrap(), next);
+            last = next;
+        }
+    }
+    #[test]
+    fn it_should_keep_increasing_when_clock_goes_backwards() {
+        let mut sut = Uuid7Gen::with_rng(StepRng::new(0, 1 << 40));
+        let first = sut.generate_at(2000).unwrap();
+        let second = sut.generate_at(2000).unwrap();
+        let third = sut.generate_at(1000).unwrap();
+        assert!(first < second && second < third);
+        assert_eq!(third.timestamp_millis(), 2000);
+        let fourth = sut.generate_at(2001).unwrap();
+        assert!(third < fourth);
+        assert_eq!(fourth.timestamp_millis(), 2001);
+    }
+    #[test]
+    fn it_should_leave_headroom_in_a_new_millisecond() {
+        // Largest possible seed and step.
+        let mut sut = Uuid7Gen::with_rng(StepRng::new(u64::MAX, 0));
+        let mut last = sut.generate_at(1000).unwrap();
+        for _ in 0..1000 {
+            let next = sut.generate_at(1000).unwrap();
+            assert!(next > last);
+            last = next;
+        }
+        assert_eq!(last.timestamp_millis(), 1000);
+        let next = sut.generate_at(1001).unwrap();
+        assert!(last < next);
+    }
 }
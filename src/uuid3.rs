@@ -0,0 +1,84 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{uuid4rfc::RFC_MASK, Namespace, Uuid};
+
+/// Version 3 and variant bits set in the RFC 9562 layout.
+pub(crate) const RFC_V3_BITS: u128 = 0x00000000000030008000000000000000;
+
+/// Name-based UUID v3 (MD5).
+///
+/// RFC 9562 prefers [Uuid5](crate::Uuid5) for new designs. This type is
+/// mainly for reproducing ids that another system already derived with MD5,
+/// like the `uuid3` function of Python or the `uuid_generate_v3` function of
+/// PostgreSQL, so both sides agree on them.
+///
+/// All 16 bytes of the digest are used in big-endian order before the
+/// version and variant bits replace 6 of them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Uuid3(u128);
+
+impl Uuid3 {
+    /// Construct a new instance from the MD5 hash of the namespace and name.
+    ///
+    /// ## Arguments
+    /// * `namespace` - One of the predefined namespaces or a custom one.
+    /// * `name` - The name to derive the value from.
+    pub fn new<N: AsRef<[u8]>>(namespace: &Namespace, name: N) -> Self {
+        let mut context = md5::Context::new();
+        context.consume(namespace.to_bytes_be());
+        context.consume(name.as_ref());
+        u128::from_be_bytes(context.compute().0).into()
+    }
+}
+
+impl_uuid_common!(
+    Uuid3,
+    u128::from_be_bytes,
+    u128::to_be_bytes,
+    RFC_MASK,
+    RFC_V3_BITS
+);
+#[cfg(feature = "serde")]
+impl_uuid_serde!(Uuid3, u128::to_be_bytes);
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+impl_uuid_interop!(Uuid3, u128::from_be_bytes, u128::to_be_bytes);
+
+impl Default for Uuid3 {
+    fn default() -> Self {
+        Self(RFC_V3_BITS)
+    }
+}
@@ -35,12 +35,15 @@
 // SOFTWARE.
 
 use crate::{Uuid, Uuid4};
-use rand::{rngs::ThreadRng, thread_rng};
+use rand::{thread_rng, CryptoRng, RngCore};
 use wasm_bindgen::prelude::*;
 
 /// Used to generate UUIDs when many are needed.
+///
+/// Uses the thread local random number generator by default but any other
+/// generator can be given with [with_rng](Uuid4Gen::with_rng).
 #[wasm_bindgen]
-pub struct Uuid4Gen(ThreadRng);
+pub struct Uuid4Gen(Box<dyn RngCore>);
 
 #[wasm_bindgen]
 impl Uuid4Gen {
@@ -49,18 +52,36 @@ impl Uuid4Gen {
         Self::default()
     }
     pub fn gen_base64(&mut self) -> String {
-        Uuid4::new(Some(&mut self.0)).as_base64()
+        Uuid4::from_rng(&mut *self.0).as_base64()
     }
     pub fn gen_hex_string(&mut self) -> String {
-        Uuid4::new(Some(&mut self.0)).as_hex_string()
+        Uuid4::from_rng(&mut *self.0).as_hex_string()
     }
     pub fn gen_uuid(&mut self) -> String {
-        Uuid4::new(Some(&mut self.0)).as_uuid()
+        Uuid4::from_rng(&mut *self.0).as_uuid()
+    }
+}
+
+impl Uuid4Gen {
+    /// Construct a generator that uses the given random number generator.
+    ///
+    /// ## Arguments
+    /// * `rng` - The random number generator to use.
+    pub fn with_rng<R: RngCore + 'static>(rng: R) -> Self {
+        Self(Box::new(rng))
+    }
+    /// Construct a generator that uses the given cryptographically secure
+    /// random number generator.
+    ///
+    /// ## Arguments
+    /// * `rng` - The random number generator to use.
+    pub fn with_crypto_rng<R: RngCore + CryptoRng + 'static>(rng: R) -> Self {
+        Self::with_rng(rng)
     }
 }
 
 impl Default for Uuid4Gen {
     fn default() -> Self {
-        Self(thread_rng())
+        Self::with_rng(thread_rng())
     }
 }
@@ -0,0 +1,127 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    uuid4::{LEGACY_BITS, LEGACY_MASK},
+    Uuid4,
+};
+#[cfg(feature = "diesel")]
+use crate::{PgUuidProxy, UuidBinaryProxy};
+#[cfg(feature = "diesel")]
+use diesel::{
+    deserialize::FromSqlRow, expression::AsExpression, query_builder::QueryId,
+    sql_types::SqlType,
+};
+
+/// Bits kept from the random value in the RFC 9562 layout.
+pub(crate) const RFC_MASK: u128 = 0xffffffffffff0fff3fffffffffffffff;
+/// Version 4 and variant bits set in the RFC 9562 layout.
+pub(crate) const RFC_V4_BITS: u128 = 0x00000000000040008000000000000000;
+
+/// Standards compliant UUID v4 (random).
+///
+/// The version `4` and variant `10xx` bits are placed where RFC 9562 (and the
+/// older RFC 4122) puts them in the big-endian (network order) layout so
+/// [as_uuid](crate::Uuid::as_uuid) gives strings other UUID tools will accept
+/// as v4.
+///
+/// Raw bytes are also treated as big-endian.
+///
+/// Values using the legacy [Uuid4] layout can be migrated with the `From`
+/// conversions in both directions. The conversion only reorders the bytes so
+/// no random bits are lost and the round trip is exact.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(AsExpression, FromSqlRow),
+    diesel(sql_type = Uuid4RfcProxy),
+    diesel(sql_type = UuidBinaryProxy),
+    diesel(sql_type = PgUuidProxy)
+)]
+pub struct Uuid4Rfc(u128);
+
+impl_uuid_common!(
+    Uuid4Rfc,
+    u128::from_be_bytes,
+    u128::to_be_bytes,
+    RFC_MASK,
+    RFC_V4_BITS
+);
+#[cfg(feature = "rand")]
+impl_uuid_random!(Uuid4Rfc);
+#[cfg(feature = "serde")]
+impl_uuid_serde!(Uuid4Rfc, u128::to_be_bytes);
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+impl_uuid_interop!(Uuid4Rfc, u128::from_be_bytes, u128::to_be_bytes);
+#[cfg(feature = "diesel")]
+impl_uuid_diesel!(Uuid4Rfc, Uuid4RfcProxy);
+
+impl Default for Uuid4Rfc {
+    fn default() -> Self {
+        Self(RFC_V4_BITS)
+    }
+}
+
+#[cfg(feature = "diesel")]
+/// Stores a [Uuid4Rfc] as its custom base 64 string in a text column.
+///
+/// Maps to `TEXT` in PostgreSQL and SQLite and `CHAR(22)` in MySQL. It is
+/// kept apart from [Uuid4Proxy](crate::Uuid4Proxy) so the schema says which
+/// layout a column holds since reading legacy [Uuid4] values as [Uuid4Rfc]
+/// would silently give different ids.
+#[derive(Clone, Copy, Debug, Default, QueryId, SqlType)]
+#[cfg_attr(
+    feature = "postgres",
+    diesel(postgres_type(oid = 25, array_oid = 1009))
+)]
+#[cfg_attr(feature = "mysql", diesel(mysql_type(name = "String")))]
+#[cfg_attr(feature = "sqlite", diesel(sqlite_type(name = "Text")))]
+pub struct Uuid4RfcProxy;
+
+impl From<Uuid4> for Uuid4Rfc {
+    /// Migrates a value from the legacy layout.
+    fn from(legacy: Uuid4) -> Self {
+        use crate::Uuid;
+        Self(legacy.uuid0().swap_bytes() & RFC_MASK | RFC_V4_BITS)
+    }
+}
+
+impl From<Uuid4Rfc> for Uuid4 {
+    /// Migrates a value back to the legacy layout.
+    fn from(rfc: Uuid4Rfc) -> Self {
+        (rfc.0.swap_bytes() & LEGACY_MASK | LEGACY_BITS).into()
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{uuid4rfc::RFC_MASK, Namespace, Uuid};
+
+/// Version 5 and variant bits set in the RFC 9562 layout.
+pub(crate) const RFC_V5_BITS: u128 = 0x00000000000050008000000000000000;
+
+/// Name-based UUID v5 (SHA-1).
+///
+/// Gives records that come from outside, like URLs or the keys of another
+/// database, a stable id without keeping a lookup table since every process
+/// that hashes the same namespace and name gets the same value.
+///
+/// Only the first 16 of the 20 bytes of the SHA-1 digest are kept. They are
+/// read in big-endian order, as are raw bytes given to the conversions.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Uuid5(u128);
+
+impl Uuid5 {
+    /// Construct a new instance from the SHA-1 hash of the namespace and name.
+    ///
+    /// ## Arguments
+    /// * `namespace` - One of the predefined namespaces or a custom one.
+    /// * `name` - The name to derive the value from.
+    pub fn new<N: AsRef<[u8]>>(namespace: &Namespace, name: N) -> Self {
+        let mut hasher = sha1_smol::Sha1::new();
+        hasher.update(&namespace.to_bytes_be());
+        hasher.update(name.as_ref());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+        u128::from_be_bytes(bytes).into()
+    }
+}
+
+impl_uuid_common!(
+    Uuid5,
+    u128::from_be_bytes,
+    u128::to_be_bytes,
+    RFC_MASK,
+    RFC_V5_BITS
+);
+#[cfg(feature = "serde")]
+impl_uuid_serde!(Uuid5, u128::to_be_bytes);
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+impl_uuid_interop!(Uuid5, u128::from_be_bytes, u128::to_be_bytes);
+
+impl Default for Uuid5 {
+    fn default() -> Self {
+        Self(RFC_V5_BITS)
+    }
+}
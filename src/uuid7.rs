@@ -0,0 +1,195 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::uuid4rfc::RFC_MASK;
+#[cfg(feature = "diesel")]
+use crate::{PgUuidProxy, UuidBinaryProxy};
+#[cfg(feature = "diesel")]
+use diesel::{
+    deserialize::FromSqlRow, expression::AsExpression, query_builder::QueryId,
+    sql_types::SqlType,
+};
+#[cfg(all(feature = "rand", feature = "std"))]
+use rand::CryptoRng;
+#[cfg(all(feature = "rand", feature = "std"))]
+use rand::{rngs::ThreadRng, thread_rng};
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore};
+#[cfg(all(feature = "rand", feature = "std"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Version 7 and variant bits set in the RFC 9562 layout.
+pub(crate) const RFC_V7_BITS: u128 = 0x00000000000070008000000000000000;
+
+/// Time-ordered UUID v7.
+///
+/// The first 48 bits hold a Unix timestamp in milliseconds and the rest,
+/// except for the version and variant bits, are random. Because the timestamp
+/// is in the most significant bits newer values compare greater than older
+/// ones which keeps database indexes from fragmenting the way random keys do.
+/// Values made in the same millisecond are in random order unless they come
+/// from a [Uuid7Gen](crate::Uuid7Gen).
+///
+/// Raw bytes are big-endian so they sort by time as well when compared
+/// byte by byte, like binary database columns do.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(AsExpression, FromSqlRow),
+    diesel(sql_type = Uuid7Proxy),
+    diesel(sql_type = UuidBinaryProxy),
+    diesel(sql_type = PgUuidProxy)
+)]
+pub struct Uuid7(u128);
+
+impl Uuid7 {
+    #[cfg(all(feature = "rand", feature = "std"))]
+    /// Construct a new instance using the current system time.
+    ///
+    /// ## Arguments
+    /// * `rng` - Optional random number generator to save startup overhead
+    ///   when generating lots of new UUIDs or other custom needs.
+    pub fn new<'a, TR>(rng: TR) -> Self
+    where
+        TR: Into<Option<&'a mut ThreadRng>>,
+    {
+        Self::from_timestamp_millis(now_millis(), rng)
+    }
+    #[cfg(all(feature = "rand", feature = "std"))]
+    /// Construct a new instance using the given timestamp.
+    ///
+    /// ## Arguments
+    /// * `millis` - Milliseconds since the Unix epoch. Only the lower 48 bits
+    ///   are used.
+    /// * `rng` - Optional random number generator to save startup overhead
+    ///   when generating lots of new UUIDs or other custom needs.
+    pub fn from_timestamp_millis<'a, TR>(millis: u64, rng: TR) -> Self
+    where
+        TR: Into<Option<&'a mut ThreadRng>>,
+    {
+        match rng.into() {
+            Some(r) => Self::from_timestamp_millis_and_rng(millis, r),
+            None => {
+                Self::from_timestamp_millis_and_rng(millis, &mut thread_rng())
+            }
+        }
+    }
+    #[cfg(all(feature = "rand", feature = "std"))]
+    /// Construct a new instance using the current system time and any random
+    /// number generator.
+    ///
+    /// ## Arguments
+    /// * `rng` - The random number generator to use.
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::from_timestamp_millis_and_rng(now_millis(), rng)
+    }
+    #[cfg(all(feature = "rand", feature = "std"))]
+    /// Construct a new instance using the current system time and a
+    /// cryptographically secure random number generator.
+    ///
+    /// ## Arguments
+    /// * `rng` - The random number generator to use.
+    pub fn from_crypto_rng<R: RngCore + CryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> Self {
+        Self::from_rng(rng)
+    }
+    #[cfg(feature = "rand")]
+    /// Construct a new instance using the given timestamp and any random
+    /// number generator.
+    ///
+    /// ## Arguments
+    /// * `millis` - Milliseconds since the Unix epoch. Only the lower 48 bits
+    ///   are used.
+    /// * `rng` - The random number generator to use.
+    pub fn from_timestamp_millis_and_rng<R: RngCore + ?Sized>(
+        millis: u64,
+        rng: &mut R,
+    ) -> Self {
+        let v: u128 = rng.gen();
+        let ts = (millis as u128 & 0xffffffffffff) << 80;
+        (ts | v & 0xffffffffffffffffffff).into()
+    }
+    /// Returns the Unix timestamp in milliseconds the value was created with.
+    pub fn timestamp_millis(&self) -> u64 {
+        (self.0 >> 80) as u64
+    }
+}
+
+/// Current system time in milliseconds since the Unix epoch.
+#[cfg(all(feature = "rand", feature = "std"))]
+pub(crate) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+impl_uuid_common!(
+    Uuid7,
+    u128::from_be_bytes,
+    u128::to_be_bytes,
+    RFC_MASK,
+    RFC_V7_BITS
+);
+#[cfg(feature = "serde")]
+impl_uuid_serde!(Uuid7, u128::to_be_bytes);
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+impl_uuid_interop!(Uuid7, u128::from_be_bytes, u128::to_be_bytes);
+#[cfg(feature = "diesel")]
+impl_uuid_diesel!(Uuid7, Uuid7Proxy);
+
+impl Default for Uuid7 {
+    fn default() -> Self {
+        Self(RFC_V7_BITS)
+    }
+}
+
+#[cfg(feature = "diesel")]
+/// Stores a [Uuid7] as its custom base 64 string in a text column.
+///
+/// Maps to `TEXT` in PostgreSQL and SQLite and `CHAR(22)` in MySQL. The base
+/// 64 alphabet is not in ASCII order so sorting the column does not follow
+/// the timestamps. Use [UuidBinaryProxy] or [PgUuidProxy] when the column is
+/// used for ordering.
+#[derive(Clone, Copy, Debug, Default, QueryId, SqlType)]
+#[cfg_attr(
+    feature = "postgres",
+    diesel(postgres_type(oid = 25, array_oid = 1009))
+)]
+#[cfg_attr(feature = "mysql", diesel(mysql_type(name = "String")))]
+#[cfg_attr(feature = "sqlite", diesel(sqlite_type(name = "Text")))]
+pub struct Uuid7Proxy;
@@ -0,0 +1,121 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Generator for strictly increasing time-ordered UUIDs.
+
+use crate::{uuid7::now_millis, U64Error, Uuid7};
+use rand::{thread_rng, CryptoRng, Rng, RngCore};
+
+/// The largest value of the 74 bit counter below the timestamp.
+const COUNTER_MAX: u128 = (1 << 74) - 1;
+/// The random start of the counter for a new millisecond.
+///
+/// The top bit is left clear so there is always room for at least
+/// 2<sup>41</sup> more values in the same millisecond as suggested by RFC 9562
+/// section 6.2.
+const COUNTER_SEED_MASK: u128 = COUNTER_MAX >> 1;
+/// The bits of the counter that go below the variant bits.
+const RAND_B_MASK: u128 = (1 << 62) - 1;
+
+/// Used to generate [Uuid7] values that are strictly increasing.
+///
+/// The 74 bits after the timestamp are treated as one counter. It starts at a
+/// random value in the lower half of its range each new millisecond and is
+/// increased by a random step of 1 to 2<sup>32</sup> for every value made
+/// within the same millisecond, so the values stay hard to guess while still
+/// sorting in the order they were made.
+///
+/// When the clock goes backwards the timestamp of the last value is kept and
+/// the counter keeps increasing until the clock catches up.
+pub struct Uuid7Gen {
+    rng: Box<dyn RngCore>,
+    last: Option<(u64, u128)>,
+}
+
+impl Uuid7Gen {
+    /// Construct a generator that uses the thread local random number
+    /// generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Construct a generator that draws the counter seeds and steps from
+    /// `rng`.
+    pub fn with_rng<R: RngCore + 'static>(rng: R) -> Self {
+        Self { rng: Box::new(rng), last: None }
+    }
+    /// Same as [with_rng](Self::with_rng) but only accepts generators that
+    /// keep the counter seeds and steps unpredictable.
+    pub fn with_crypto_rng<R: RngCore + CryptoRng + 'static>(rng: R) -> Self {
+        Self::with_rng(rng)
+    }
+    /// Generate a value greater than any made before by this generator using
+    /// the current system time.
+    ///
+    /// Returns [CounterOverflow](U64Error::CounterOverflow) when the counter
+    /// runs out within one millisecond. Nothing changes in that case so
+    /// trying again once the clock moved on will succeed.
+    pub fn generate(&mut self) -> Result<Uuid7, U64Error> {
+        self.generate_at(now_millis())
+    }
+    /// Generate a value greater than any made before by this generator using
+    /// the given timestamp.
+    ///
+    /// ## Arguments
+    /// * `millis` - Milliseconds since the Unix epoch. Only the lower 48 bits
+    ///   are used.
+    pub fn generate_at(&mut self, millis: u64) -> Result<Uuid7, U64Error> {
+        let millis = millis & 0xffffffffffff;
+        let (millis, counter) = match self.last {
+            // Same millisecond or the clock went backwards.
+            Some((last, counter)) if millis <= last => {
+                let step = u128::from(self.rng.next_u32()) + 1;
+                if counter + step > COUNTER_MAX {
+                    return Err(U64Error::CounterOverflow);
+                }
+                (last, counter + step)
+            }
+            _ => (millis, self.rng.gen::<u128>() & COUNTER_SEED_MASK),
+        };
+        self.last = Some((millis, counter));
+        let rand_a = counter >> 62 << 64;
+        Ok(Uuid7::from((millis as u128) << 80 | rand_a | counter & RAND_B_MASK))
+    }
+}
+
+impl Default for Uuid7Gen {
+    fn default() -> Self {
+        Self::with_rng(thread_rng())
+    }
+}
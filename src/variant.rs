@@ -0,0 +1,68 @@
+// Copyright © 2020-present, Michael Cummings
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! The variant field that decides how the rest of the bits are laid out.
+
+/// The variant of a UUID as defined by RFC 9562.
+///
+/// All of the types in the library produce [Variant::Rfc]. The others can
+/// only show up in values created with `from_u128_unchecked` or parsed by
+/// other libraries.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Variant {
+    /// Reserved for backward compatibility with Apollo NCS UUIDs (`0xx`).
+    Ncs,
+    /// The layout of RFC 9562 used by this library (`10x`).
+    Rfc,
+    /// Reserved for backward compatibility with old Microsoft GUIDs (`110`).
+    Microsoft,
+    /// Reserved for future definition (`111`).
+    Future,
+}
+
+impl Variant {
+    /// Reads the variant from the top bits of the clock sequence byte.
+    ///
+    /// ## Arguments
+    /// * `byte` - Byte 8 of the value in the RFC 9562 byte order.
+    pub(crate) const fn from_byte(byte: u8) -> Self {
+        match byte >> 5 {
+            0..=3 => Variant::Ncs,
+            4 | 5 => Variant::Rfc,
+            6 => Variant::Microsoft,
+            _ => Variant::Future,
+        }
+    }
+}